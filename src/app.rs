@@ -1,9 +1,18 @@
 use eframe::egui;
-use log::info;
+use log::{info, warn};
+use std::time::Duration;
 
-use crate::game::GameState;
+use valorant_manager::game::{AutosaveSettings, GameState, SaveManager};
+use crate::legacy::ron_to_json;
 use crate::ui::{MainMenuScreen, SquadScreen, TacticsScreen, FinanceScreen, ScheduleScreen, ScoutingScreen};
 
+/// eframe storage key for the autosave settings. `APP_KEY` held the whole
+/// career before save slots existed.
+const AUTOSAVE_SETTINGS_KEY: &str = "autosave_settings";
+
+/// Slot a career found under `APP_KEY` is moved into.
+const IMPORTED_CAREER_SLOT: &str = "Imported career";
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Screen {
     MainMenu,
@@ -18,6 +27,14 @@ pub enum Screen {
 pub struct ValorantManagerApp {
     current_screen: Screen,
     game_state: GameState,
+    save_manager: SaveManager,
+    autosave_settings: AutosaveSettings,
+    clear_legacy_career: bool, // Drop the old career from eframe storage on the next save
+
+    // Save dialog
+    show_save_dialog: bool,
+    save_slot_name: String,
+    save_status: Option<String>,
     
     // UI Screens
     main_menu: MainMenuScreen,
@@ -32,20 +49,30 @@ impl ValorantManagerApp {
     pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
         info!("Initializing Valorant Manager App");
         
-        // Load persistent data if available
-        // Temporarily force new game state to get updated budget
+        // Careers are picked from the save slots in the main menu; eframe
+        // storage only holds the app settings
         let game_state = GameState::new();
-        
-        // TODO: Restore persistence after budget testing
-        // let game_state = if let Some(storage) = cc.storage {
-        //     eframe::get_value(storage, eframe::APP_KEY).unwrap_or_default()
-        // } else {
-        //     GameState::new()
-        // };
+        let save_manager = SaveManager::new(SaveManager::default_dir());
+        let autosave_settings = cc
+            .storage
+            .and_then(|storage| {
+                eframe::get_value(storage, AUTOSAVE_SETTINGS_KEY)
+                    .or_else(|| eframe::get_value(storage, eframe::APP_KEY))
+            })
+            .unwrap_or_default();
+        let clear_legacy_career = cc
+            .storage
+            .is_some_and(|storage| Self::import_legacy_career(storage, &save_manager));
 
         Self {
             current_screen: Screen::MainMenu,
             game_state,
+            save_manager,
+            autosave_settings,
+            clear_legacy_career,
+            show_save_dialog: false,
+            save_slot_name: String::new(),
+            save_status: None,
             main_menu: MainMenuScreen::new(),
             squad_screen: SquadScreen::new(),
             tactics_screen: TacticsScreen::new(),
@@ -55,6 +82,32 @@ impl ValorantManagerApp {
         }
     }
 
+    /// Moves a career eframe kept under `APP_KEY` into a save slot. Returns
+    /// true once there's nothing left there worth keeping.
+    fn import_legacy_career(storage: &dyn eframe::Storage, save_manager: &SaveManager) -> bool {
+        let Some(state) = storage.get_string(eframe::APP_KEY).and_then(|ron| ron_to_json(&ron)) else {
+            return false;
+        };
+        // Only a career with a team is worth a slot, and one imported before
+        // isn't imported over again
+        if state.get("current_team").is_none_or(|team| team.is_null())
+            || save_manager.list_saves().iter().any(|s| s.slot == IMPORTED_CAREER_SLOT)
+        {
+            return true;
+        }
+
+        match save_manager.import(IMPORTED_CAREER_SLOT, state) {
+            Ok(_) => {
+                info!("Imported the career from app storage into \"{}\"", IMPORTED_CAREER_SLOT);
+                true
+            }
+            Err(err) => {
+                warn!("Could not import the career from app storage: {}", err);
+                false
+            }
+        }
+    }
+
     fn render_top_bar(&mut self, ctx: &egui::Context) {
         egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
             egui::menu::bar(ui, |ui| {
@@ -79,6 +132,18 @@ impl ValorantManagerApp {
                 if ui.selectable_label(self.current_screen == Screen::Scouting, "Scouting").clicked() {
                     self.current_screen = Screen::Scouting;
                 }
+
+                ui.separator();
+
+                if ui.button("Save Game").clicked() {
+                    self.show_save_dialog = true;
+                    self.save_status = None;
+                    if self.save_slot_name.is_empty() {
+                        if let Some(team) = &self.game_state.current_team {
+                            self.save_slot_name = format!("{} {}", team.nickname, self.game_state.current_season);
+                        }
+                    }
+                }
                 
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                     // Game info
@@ -93,6 +158,73 @@ impl ValorantManagerApp {
             });
         });
     }
+
+    /// Writes a rotating autosave if anything the player did this frame
+    /// asked for one.
+    fn run_autosave(&mut self) {
+        let requests = self.game_state.take_autosave_requests();
+        if !requests.iter().any(|&trigger| self.autosave_settings.should_save(trigger)) {
+            return;
+        }
+
+        match self.save_manager.autosave(&self.game_state, self.autosave_settings.backups) {
+            Ok(_) => info!("Autosaved after {:?}", requests),
+            Err(err) => warn!("Autosave failed: {}", err),
+        }
+    }
+
+    fn render_save_dialog(&mut self, ctx: &egui::Context) {
+        if !self.show_save_dialog {
+            return;
+        }
+
+        egui::Window::new("Save Game")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Save name:");
+                    ui.text_edit_singleline(&mut self.save_slot_name);
+                });
+
+                ui.label(format!("Career seed: {}", self.game_state.seed));
+
+                if let Some(status) = &self.save_status {
+                    ui.label(status);
+                }
+
+                ui.add_space(10.0);
+                ui.heading("Autosave");
+                let settings = &mut self.autosave_settings;
+                ui.checkbox(&mut settings.enabled, "Enable autosave");
+                ui.add_enabled_ui(settings.enabled, |ui| {
+                    ui.checkbox(&mut settings.on_week_advance, "When advancing a week");
+                    ui.checkbox(&mut settings.after_match, "After each match");
+                    ui.checkbox(&mut settings.before_season_rollover, "Before a new season starts");
+                    ui.horizontal(|ui| {
+                        ui.label("Backups to keep:");
+                        ui.add(egui::DragValue::new(&mut settings.backups).range(0..=10));
+                    });
+                });
+
+                ui.add_space(10.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Save").clicked() {
+                        self.save_status = Some(match self.save_manager.save(&self.save_slot_name, &self.game_state) {
+                            Ok(summary) => {
+                                info!("Saved game to slot {}", summary.slot);
+                                format!("Saved \"{}\"", summary.slot)
+                            }
+                            Err(err) => format!("Save failed: {}", err),
+                        });
+                    }
+                    if ui.button("Close").clicked() {
+                        self.show_save_dialog = false;
+                    }
+                });
+            });
+    }
 }
 
 impl eframe::App for ValorantManagerApp {
@@ -100,12 +232,13 @@ impl eframe::App for ValorantManagerApp {
         // Only show top bar if not on main menu
         if self.current_screen != Screen::MainMenu {
             self.render_top_bar(ctx);
+            self.render_save_dialog(ctx);
         }
 
         // Render current screen
         match self.current_screen {
             Screen::MainMenu => {
-                if self.main_menu.show(ctx, &mut self.game_state) {
+                if self.main_menu.show(ctx, &mut self.game_state, &self.save_manager) {
                     self.current_screen = Screen::Squad;
                 }
             }
@@ -128,9 +261,30 @@ impl eframe::App for ValorantManagerApp {
                 // TODO: Implement match screen
             }
         }
+
+        self.run_autosave();
     }
 
     fn save(&mut self, storage: &mut dyn eframe::Storage) {
-        eframe::set_value(storage, eframe::APP_KEY, &self.game_state);
+        eframe::set_value(storage, AUTOSAVE_SETTINGS_KEY, &self.autosave_settings);
+        if self.clear_legacy_career {
+            storage.set_string(eframe::APP_KEY, String::new());
+            self.clear_legacy_career = false;
+        }
+
+        // Also called on exit, so an unexpected close loses at most one
+        // interval of progress. An idle career isn't written again.
+        if self.autosave_settings.enabled && self.game_state.current_team.is_some() {
+            if let Err(err) = self
+                .save_manager
+                .autosave_if_changed(&self.game_state, self.autosave_settings.backups)
+            {
+                warn!("Autosave on exit failed: {}", err);
+            }
+        }
+    }
+
+    fn auto_save_interval(&self) -> Duration {
+        Duration::from_secs(5 * 60)
     }
 } 
\ No newline at end of file
@@ -0,0 +1,307 @@
+//! Headless season simulator for balance testing.
+//!
+//! Builds a world with every team AI-controlled, plays out whole VCT seasons
+//! and prints the final standings, the Champions winner and the stat leaders.
+
+use std::collections::HashMap;
+use std::process::ExitCode;
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use valorant_manager::game::{
+    aggregate_player_stats, GameState, PlayerSeasonStats, VCTEvent,
+};
+
+const USAGE: &str = "Usage: vm-sim [--seed <n>] [--seasons <n>] [--format text|json]
+
+Options:
+  --seed <n>       Seed for the simulation (default: random)
+  --seasons <n>    Number of seasons to simulate (default: 1)
+  --format <fmt>   Output as plain text or JSON (default: text)
+  -h, --help       Show this message";
+
+/// Maps a player needs before they count for the stat leaders.
+const MIN_MAPS_FOR_LEADERS: u32 = 10;
+const LEADERS_SHOWN: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum OutputFormat {
+    Text,
+    Json,
+}
+
+struct Options {
+    seed: u64,
+    seasons: u32,
+    format: OutputFormat,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Option<Self>, String> {
+        let mut options = Self {
+            seed: rand::random(),
+            seasons: 1,
+            format: OutputFormat::Text,
+        };
+
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| args.next().ok_or_else(|| format!("{} needs a value", name));
+            match arg.as_str() {
+                "--seed" => {
+                    options.seed = value("--seed")?.parse().map_err(|_| "--seed must be a number".to_string())?;
+                }
+                "--seasons" => {
+                    options.seasons = value("--seasons")?
+                        .parse()
+                        .ok()
+                        .filter(|&n| n > 0)
+                        .ok_or_else(|| "--seasons must be a positive number".to_string())?;
+                }
+                "--format" => {
+                    options.format = match value("--format")?.as_str() {
+                        "text" => OutputFormat::Text,
+                        "json" => OutputFormat::Json,
+                        other => return Err(format!("unknown format '{}'", other)),
+                    };
+                }
+                "-h" | "--help" => return Ok(None),
+                other => return Err(format!("unknown argument '{}'", other)),
+            }
+        }
+
+        Ok(Some(options))
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct SimulationReport {
+    seed: u64,
+    seasons: Vec<SeasonReport>,
+}
+
+#[derive(Debug, Serialize)]
+struct SeasonReport {
+    season: u32,
+    champion: Option<String>,
+    standings: Vec<StandingRow>,
+    leaders: StatLeaders,
+}
+
+#[derive(Debug, Serialize)]
+struct StandingRow {
+    team: String,
+    region: String,
+    points: u32,
+    wins: u32,
+    losses: u32,
+    map_differential: i32,
+    round_differential: i32,
+}
+
+#[derive(Debug, Serialize)]
+struct StatLeaders {
+    kills: Vec<LeaderRow>,
+    acs: Vec<LeaderRow>,
+    kdr: Vec<LeaderRow>,
+    adr: Vec<LeaderRow>,
+}
+
+#[derive(Debug, Serialize)]
+struct LeaderRow {
+    player: String,
+    team: String,
+    maps: u32,
+    value: f32,
+}
+
+fn main() -> ExitCode {
+    let options = match Options::parse(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(err) => {
+            eprintln!("vm-sim: {}\n\n{}", err, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let report = run(&options);
+    match options.format {
+        OutputFormat::Text => print_text(&report),
+        OutputFormat::Json => match serde_json::to_string_pretty(&report) {
+            Ok(json) => println!("{}", json),
+            Err(err) => {
+                eprintln!("vm-sim: could not serialize report: {}", err);
+                return ExitCode::FAILURE;
+            }
+        },
+    }
+    ExitCode::SUCCESS
+}
+
+fn run(options: &Options) -> SimulationReport {
+    let mut state = GameState::with_seed(options.seed);
+    state.initialize_world();
+
+    let mut seasons = Vec::new();
+    for season in 0..options.seasons {
+        if season > 0 {
+            while !state.is_rollover_due() {
+                state.advance_day();
+            }
+            state.start_new_season();
+        }
+        let first_result = state.match_history.len();
+        let champion = simulate_season(&mut state);
+        seasons.push(season_report(&state, champion, first_result));
+    }
+
+    SimulationReport {
+        seed: options.seed,
+        seasons,
+    }
+}
+
+/// Plays one season day by day until Champions is over and returns its
+/// winner.
+fn simulate_season(state: &mut GameState) -> Option<Uuid> {
+    while !state.tournament_state.is_season_complete() {
+        state.advance_day();
+    }
+    state
+        .tournament_state
+        .event_winner(state.current_season, VCTEvent::ChampionsParis)
+}
+
+fn season_report(state: &GameState, champion: Option<Uuid>, first_result: usize) -> SeasonReport {
+    let team_name = |id: Uuid| state.get_team(id).map(|t| t.name.clone()).unwrap_or_else(|| "Unknown".to_string());
+
+    let standings = state
+        .standings()
+        .into_iter()
+        .map(|(team, standing)| StandingRow {
+            team: team.name.clone(),
+            region: team.region.clone(),
+            points: standing.points,
+            wins: standing.wins,
+            losses: standing.losses,
+            map_differential: standing.map_differential(),
+            round_differential: standing.round_differential(),
+        })
+        .collect();
+
+    let player_teams: HashMap<Uuid, Uuid> = state
+        .all_teams
+        .iter()
+        .flat_map(|team| team.players.iter().map(move |&player_id| (player_id, team.id)))
+        .collect();
+
+    let stats: Vec<PlayerSeasonStats> = aggregate_player_stats(&state.match_history[first_result..])
+        .into_iter()
+        .filter(|s| s.maps_played >= MIN_MAPS_FOR_LEADERS)
+        .collect();
+    let leaders = |value: fn(&PlayerSeasonStats) -> f32| {
+        let mut rows: Vec<LeaderRow> = stats
+            .iter()
+            .map(|s| LeaderRow {
+                player: state
+                    .get_player_by_id(s.player_id)
+                    .map(|p| p.name.clone())
+                    .unwrap_or_else(|| "Unknown".to_string()),
+                team: player_teams.get(&s.player_id).map(|&id| team_name(id)).unwrap_or_default(),
+                maps: s.maps_played,
+                value: value(s),
+            })
+            .collect();
+        rows.sort_by(|a, b| b.value.total_cmp(&a.value));
+        rows.truncate(LEADERS_SHOWN);
+        rows
+    };
+
+    SeasonReport {
+        season: state.current_season,
+        champion: champion.map(team_name),
+        standings,
+        leaders: StatLeaders {
+            kills: leaders(|s| s.kills as f32),
+            acs: leaders(PlayerSeasonStats::acs),
+            kdr: leaders(PlayerSeasonStats::kdr),
+            adr: leaders(PlayerSeasonStats::adr),
+        },
+    }
+}
+
+fn print_text(report: &SimulationReport) {
+    println!("Seed: {}", report.seed);
+
+    for season in &report.seasons {
+        println!();
+        println!("=== Season {} ===", season.season);
+        println!("Champions winner: {}", season.champion.as_deref().unwrap_or("none"));
+
+        println!();
+        println!("Final standings");
+        for (i, row) in season.standings.iter().enumerate() {
+            println!(
+                "{:>3}. {:<24} {:<9} {:>3} pts  {:>2}-{:<2}  maps {:>+4}  rounds {:>+5}",
+                i + 1,
+                row.team,
+                row.region,
+                row.points,
+                row.wins,
+                row.losses,
+                row.map_differential,
+                row.round_differential
+            );
+        }
+
+        println!();
+        println!("Stat leaders (min. {} maps)", MIN_MAPS_FOR_LEADERS);
+        for (title, rows, decimals) in [
+            ("Kills", &season.leaders.kills, 0),
+            ("ACS", &season.leaders.acs, 1),
+            ("K/D", &season.leaders.kdr, 2),
+            ("ADR", &season.leaders.adr, 1),
+        ] {
+            println!("  {}", title);
+            for row in rows {
+                println!(
+                    "    {:<24} {:<24} {:>8.*} ({} maps)",
+                    row.player, row.team, decimals, row.value, row.maps
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(args: &[&str]) -> Options {
+        Options::parse(args.iter().map(|a| a.to_string())).unwrap().unwrap()
+    }
+
+    #[test]
+    fn the_same_seed_plays_the_same_season() {
+        let options = options(&["--seed", "42", "--format", "json"]);
+        let first = serde_json::to_string(&run(&options)).unwrap();
+        let second = serde_json::to_string(&run(&options)).unwrap();
+        assert_eq!(first, second);
+
+        let other = serde_json::to_string(&run(&Options { seed: 43, ..options })).unwrap();
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn bad_arguments_are_refused() {
+        for args in [&["--seed", "x"][..], &["--seasons", "0"], &["--format", "xml"], &["--seed"], &["--fast"]] {
+            assert!(Options::parse(args.iter().map(|a| a.to_string())).is_err(), "{:?}", args);
+        }
+        assert_eq!(options(&["--seed", "7"]).seed, 7);
+        assert!(Options::parse(["--help".to_string()].into_iter()).unwrap().is_none());
+    }
+}
@@ -0,0 +1,156 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game::player::AgentRole;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum AbilityType {
+    Flash,
+    Smoke,
+    Recon,
+    Molly,
+    Wall,
+    Trap,
+    Heal,
+    Mobility,
+    Concuss,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Agent {
+    // Duelists
+    Jett,
+    Raze,
+    Reyna,
+    Neon,
+    Yoru,
+    // Initiators
+    Sova,
+    Breach,
+    Skye,
+    KayO,
+    Fade,
+    // Controllers
+    Brimstone,
+    Omen,
+    Viper,
+    Astra,
+    Harbor,
+    // Sentinels
+    Sage,
+    Cypher,
+    Killjoy,
+    Chamber,
+    Deadlock,
+}
+
+impl Agent {
+    pub const ALL: [Agent; 20] = [
+        Agent::Jett, Agent::Raze, Agent::Reyna, Agent::Neon, Agent::Yoru,
+        Agent::Sova, Agent::Breach, Agent::Skye, Agent::KayO, Agent::Fade,
+        Agent::Brimstone, Agent::Omen, Agent::Viper, Agent::Astra, Agent::Harbor,
+        Agent::Sage, Agent::Cypher, Agent::Killjoy, Agent::Chamber, Agent::Deadlock,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Agent::Jett => "Jett",
+            Agent::Raze => "Raze",
+            Agent::Reyna => "Reyna",
+            Agent::Neon => "Neon",
+            Agent::Yoru => "Yoru",
+            Agent::Sova => "Sova",
+            Agent::Breach => "Breach",
+            Agent::Skye => "Skye",
+            Agent::KayO => "KAY/O",
+            Agent::Fade => "Fade",
+            Agent::Brimstone => "Brimstone",
+            Agent::Omen => "Omen",
+            Agent::Viper => "Viper",
+            Agent::Astra => "Astra",
+            Agent::Harbor => "Harbor",
+            Agent::Sage => "Sage",
+            Agent::Cypher => "Cypher",
+            Agent::Killjoy => "Killjoy",
+            Agent::Chamber => "Chamber",
+            Agent::Deadlock => "Deadlock",
+        }
+    }
+
+    pub fn role(&self) -> AgentRole {
+        match self {
+            Agent::Jett | Agent::Raze | Agent::Reyna | Agent::Neon | Agent::Yoru => AgentRole::Duelist,
+            Agent::Sova | Agent::Breach | Agent::Skye | Agent::KayO | Agent::Fade => AgentRole::Initiator,
+            Agent::Brimstone | Agent::Omen | Agent::Viper | Agent::Astra | Agent::Harbor => AgentRole::Controller,
+            Agent::Sage | Agent::Cypher | Agent::Killjoy | Agent::Chamber | Agent::Deadlock => AgentRole::Sentinel,
+        }
+    }
+
+    pub fn signature_abilities(&self) -> &'static [AbilityType] {
+        use AbilityType::*;
+        match self {
+            Agent::Jett => &[Mobility, Smoke],
+            Agent::Raze => &[Mobility, Molly],
+            Agent::Reyna => &[Flash, Heal],
+            Agent::Neon => &[Mobility, Wall, Concuss],
+            Agent::Yoru => &[Flash, Mobility],
+            Agent::Sova => &[Recon, Molly],
+            Agent::Breach => &[Flash, Concuss],
+            Agent::Skye => &[Flash, Recon, Heal],
+            Agent::KayO => &[Flash, Molly],
+            Agent::Fade => &[Recon, Concuss],
+            Agent::Brimstone => &[Smoke, Molly],
+            Agent::Omen => &[Smoke, Flash, Mobility],
+            Agent::Viper => &[Smoke, Wall, Molly],
+            Agent::Astra => &[Smoke, Concuss],
+            Agent::Harbor => &[Smoke, Wall],
+            Agent::Sage => &[Wall, Heal],
+            Agent::Cypher => &[Trap, Recon],
+            Agent::Killjoy => &[Trap, Molly],
+            Agent::Chamber => &[Trap, Mobility],
+            Agent::Deadlock => &[Trap, Wall],
+        }
+    }
+
+    /// Maps where the agent's kit is a particularly good fit.
+    pub fn favored_maps(&self) -> &'static [&'static str] {
+        match self {
+            Agent::Jett => &["Ascent", "Breeze", "Icebox"],
+            Agent::Raze => &["Bind", "Split", "Lotus"],
+            Agent::Reyna => &["Split"],
+            Agent::Neon => &["Fracture", "Bind"],
+            Agent::Yoru => &["Bind"],
+            Agent::Sova => &["Ascent", "Breeze", "Icebox", "Haven"],
+            Agent::Breach => &["Fracture", "Haven", "Lotus"],
+            Agent::Skye => &["Split", "Bind"],
+            Agent::KayO => &["Ascent", "Icebox"],
+            Agent::Fade => &["Lotus", "Haven", "Bind"],
+            Agent::Brimstone => &["Bind", "Fracture"],
+            Agent::Omen => &["Ascent", "Haven", "Lotus", "Split"],
+            Agent::Viper => &["Breeze", "Icebox", "Bind", "Split"],
+            Agent::Astra => &["Split", "Lotus"],
+            Agent::Harbor => &["Breeze", "Fracture"],
+            Agent::Sage => &["Icebox", "Bind"],
+            Agent::Cypher => &["Split", "Breeze", "Bind"],
+            Agent::Killjoy => &["Ascent", "Haven", "Lotus", "Icebox"],
+            Agent::Chamber => &["Breeze", "Fracture"],
+            Agent::Deadlock => &["Haven", "Lotus"],
+        }
+    }
+
+    /// How well the agent suits a map, on the usual 1-20 scale.
+    pub fn map_affinity(&self, map: &str) -> u8 {
+        if self.favored_maps().contains(&map) {
+            16
+        } else {
+            10
+        }
+    }
+
+    pub fn has_ability(&self, ability: AbilityType) -> bool {
+        self.signature_abilities().contains(&ability)
+    }
+
+    pub fn agents_for_role(role: AgentRole) -> impl Iterator<Item = Agent> {
+        Self::ALL.into_iter().filter(move |agent| agent.role() == role)
+    }
+}
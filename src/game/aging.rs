@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+use rand::Rng;
+
+use crate::game::player::{Player, PlayerAttributes};
+use crate::game::stats::PlayerSeasonStats;
+
+/// Last age at which a player's reflexes are still at their best.
+const PEAK_AGE: u8 = 26;
+
+/// Maps a season below which a player counts as out of the game.
+const REGULAR_MAPS: u32 = 10;
+
+/// A player who has left the game, kept for the record books.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetiredPlayer {
+    pub player: Player,
+    pub season: u32,
+    pub career: PlayerSeasonStats,
+}
+
+/// Applies a birthday's worth of change to a player who has just turned a
+/// year older. Past their peak aim and movement fade, while game sense and
+/// communication can keep improving well into a career.
+pub fn age_player<R: Rng + ?Sized>(player: &mut Player, rng: &mut R) {
+    let years_past_peak = player.age.saturating_sub(PEAK_AGE);
+    if years_past_peak > 0 {
+        let decline_chance = (years_past_peak as f64 * 0.2).min(0.9);
+        let reflexes: [fn(&mut PlayerAttributes) -> &mut u8; 2] = [|a| &mut a.aim, |a| &mut a.movement];
+        for attribute in reflexes {
+            if rng.gen_bool(decline_chance) {
+                let value = attribute(&mut player.attributes);
+                *value = value.saturating_sub(1).max(1);
+            }
+        }
+        if player.age > 30 && rng.gen_bool(decline_chance) {
+            player.attributes.stamina = player.attributes.stamina.saturating_sub(1).max(1);
+        }
+    }
+
+    if player.age >= 22 {
+        let growth_chance = if player.age <= 32 { 0.3 } else { 0.1 };
+        let mental: [fn(&mut PlayerAttributes) -> &mut u8; 2] = [|a| &mut a.game_sense, |a| &mut a.communication];
+        for attribute in mental {
+            if rng.gen_bool(growth_chance) {
+                let value = attribute(&mut player.attributes);
+                *value = (*value + 1).min(20);
+            }
+        }
+    }
+
+    player.update_ratings();
+}
+
+/// Chance a player hangs up the mouse this off-season. Age matters most;
+/// players out of contract or out of the side are readier to go, and a
+/// strong season keeps veterans around.
+pub fn retirement_chance(player: &Player, contract_years_left: u8, season: Option<&PlayerSeasonStats>) -> f64 {
+    let maps_played = season.map_or(0, |s| s.maps_played);
+    let out_of_contract = contract_years_left == 0;
+
+    let mut chance: f64 = match player.age {
+        0..=23 => 0.0,
+        24..=28 => if out_of_contract && maps_played == 0 { 0.08 } else { 0.0 },
+        29..=30 => 0.05,
+        31..=32 => 0.15,
+        33..=34 => 0.35,
+        _ => 0.6,
+    };
+    if chance <= 0.0 {
+        return 0.0;
+    }
+
+    if out_of_contract {
+        chance += 0.15;
+    } else if contract_years_left >= 2 {
+        chance *= 0.5;
+    }
+
+    match season {
+        Some(stats) if stats.maps_played >= REGULAR_MAPS => {
+            if stats.acs() >= 230.0 {
+                chance *= 0.5;
+            } else if stats.acs() < 180.0 {
+                chance += 0.05;
+            }
+        }
+        _ => chance += 0.1,
+    }
+
+    chance.min(0.95)
+}
@@ -0,0 +1,130 @@
+use std::cmp::Reverse;
+use uuid::Uuid;
+
+use crate::game::player::{AgentRole, Player};
+use crate::game::team::{Team, TeamComposition};
+
+/// What an AI club earns over a season from sponsors and streaming, before
+/// wages.
+pub const AI_SEASON_INCOME: i64 = 600_000;
+
+/// Players an AI club carries: a starting five and a substitute.
+pub const AI_SQUAD_SIZE: usize = 6;
+
+/// Share of its funds an AI club will put into a single signing.
+pub const MAX_SPEND_SHARE: f64 = 0.6;
+
+/// How much better than the weakest of the first five a signing has to be
+/// before a club goes after them.
+pub const SIGNING_MARGIN: u16 = 15;
+
+/// Chance of an AI club doing business in a given transfer window.
+pub const WINDOW_ACTIVITY_CHANCE: f64 = 0.6;
+
+/// Below this share of series won, a season counts as a failure and the
+/// club starts to rebuild.
+const REBUILD_WIN_RATE: f32 = 0.4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClubStrategy {
+    /// Chasing results now: signs proven players.
+    Contend,
+    /// Coming off a bad season: lets veterans go and signs for the future.
+    Rebuild,
+}
+
+impl ClubStrategy {
+    pub fn for_season(win_rate: f32) -> Self {
+        if win_rate < REBUILD_WIN_RATE {
+            ClubStrategy::Rebuild
+        } else {
+            ClubStrategy::Contend
+        }
+    }
+
+    /// What a player is worth to a club with this strategy. Contenders go
+    /// by current ability; rebuilders by what young players could become,
+    /// and discount anyone past their early twenties.
+    pub fn player_value(&self, player: &Player) -> u16 {
+        match self {
+            ClubStrategy::Contend => player.current_ability as u16,
+            ClubStrategy::Rebuild if player.age <= 23 => {
+                (player.current_ability as u16 + player.potential_ability as u16) / 2
+            }
+            ClubStrategy::Rebuild => player.current_ability as u16 * 3 / 4,
+        }
+    }
+
+    /// Chance of a club offering a new deal to a player whose contract has
+    /// run out. Clubs keep their first five and let the bench go; rebuilding
+    /// clubs also move on from their veterans.
+    pub fn renewal_chance(&self, player: &Player, team: &Team, players: &[Player]) -> f64 {
+        let rank = ranked_squad(team, players, *self)
+            .iter()
+            .position(|p| p.id == player.id)
+            .unwrap_or(usize::MAX);
+        let chance = if rank < 5 { 0.9 } else { 0.3 };
+        match self {
+            ClubStrategy::Rebuild if player.age > 27 => chance * 0.4,
+            _ if player.age > 30 => chance * 0.6,
+            _ => chance,
+        }
+    }
+}
+
+/// A team's players, most valuable to the club first.
+pub fn ranked_squad<'a>(team: &Team, players: &'a [Player], strategy: ClubStrategy) -> Vec<&'a Player> {
+    let mut squad: Vec<&Player> = team
+        .players
+        .iter()
+        .filter_map(|id| players.iter().find(|p| p.id == *id))
+        .collect();
+    squad.sort_by_key(|p| Reverse(strategy.player_value(p)));
+    squad
+}
+
+/// Roles none of a team's players specialise in.
+pub fn missing_roles(team: &Team, players: &[Player]) -> Vec<AgentRole> {
+    [AgentRole::Duelist, AgentRole::Initiator, AgentRole::Controller, AgentRole::Sentinel]
+        .into_iter()
+        .filter(|role| {
+            !team
+                .players
+                .iter()
+                .filter_map(|id| players.iter().find(|p| p.id == *id))
+                .any(|p| p.preferred_role == *role)
+        })
+        .collect()
+}
+
+/// Picks an AI team's starting five from its available players: the best
+/// specialist in each role first, then the strongest of the rest without
+/// stacking more than two duelists.
+pub fn pick_lineup(team: &Team, players: &[Player]) -> Vec<Uuid> {
+    let mut available: Vec<&Player> = team
+        .players
+        .iter()
+        .filter_map(|id| players.iter().find(|p| p.id == *id))
+        .filter(|p| p.is_available())
+        .collect();
+    available.sort_by_key(|p| Reverse(p.current_ability));
+
+    let mut lineup = Vec::new();
+    let mut composition = TeamComposition::new();
+    for role in [AgentRole::Controller, AgentRole::Sentinel, AgentRole::Initiator, AgentRole::Duelist] {
+        if let Some(index) = available.iter().position(|p| p.preferred_role == role) {
+            lineup.push(available.remove(index));
+            composition.add_role(role);
+        }
+    }
+    while lineup.len() < 5 && !available.is_empty() {
+        let index = available
+            .iter()
+            .position(|p| p.preferred_role != AgentRole::Duelist || composition.duelist_count < 2)
+            .unwrap_or(0);
+        let player = available.remove(index);
+        composition.add_role(player.preferred_role);
+        lineup.push(player);
+    }
+    lineup.into_iter().map(|p| p.id).collect()
+}
@@ -0,0 +1,830 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::game::series::SeriesFormat;
+
+/// Where a bracket match gets one of its teams from.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Slot {
+    Team(Uuid),
+    Winner(usize), // Index of an earlier match in the same stage
+    Loser(usize),
+    Bye, // Nobody - the other team goes through without playing
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum StageFormat {
+    RoundRobin,
+    SingleElimination,
+    DoubleElimination,
+    TripleElimination,
+    Swiss,
+    Gsl,
+}
+
+/// Wins that take a team through a Swiss stage, and losses that knock it out.
+const SWISS_WINS_TO_ADVANCE: u32 = 2;
+const SWISS_LOSSES_TO_EXIT: u32 = 2;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BracketResult {
+    pub winner_id: Uuid,
+    pub loser_id: Uuid,
+    pub winner_maps: u8,
+    pub loser_maps: u8,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BracketMatch {
+    pub label: String,
+    pub round: u8,
+    pub slots: [Slot; 2],
+    pub format: SeriesFormat,
+    pub fixture_id: Option<Uuid>,
+    pub result: Option<BracketResult>,
+}
+
+impl BracketMatch {
+    fn new(label: String, round: u8, slots: [Slot; 2], format: SeriesFormat) -> Self {
+        Self {
+            label,
+            round,
+            slots,
+            format,
+            fixture_id: None,
+            result: None,
+        }
+    }
+}
+
+/// One part of an event - a group or a playoff bracket - and the matches
+/// played in it. Matches whose teams aren't known yet point at earlier
+/// matches through their slots and become playable once those are decided.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Stage {
+    pub name: String,
+    pub region: Option<String>,
+    pub format: StageFormat,
+    pub teams: Vec<Uuid>, // In seed order
+    pub matches: Vec<BracketMatch>,
+    pub start_date: DateTime<Utc>,
+    pub days_between_rounds: i64,
+}
+
+impl Stage {
+    /// Every team plays every other team once, scheduled with the circle
+    /// method so each team plays at most once per round.
+    pub fn round_robin(name: String, region: Option<String>, teams: Vec<Uuid>, format: SeriesFormat) -> Self {
+        let mut rotation: Vec<Option<Uuid>> = teams.iter().copied().map(Some).collect();
+        if rotation.len() % 2 == 1 {
+            rotation.push(None); // Bye
+        }
+
+        let mut matches = Vec::new();
+        let rounds = rotation.len().saturating_sub(1);
+        for round in 0..rounds {
+            let half = rotation.len() / 2;
+            for i in 0..half {
+                if let (Some(team1), Some(team2)) = (rotation[i], rotation[rotation.len() - 1 - i]) {
+                    matches.push(BracketMatch::new(
+                        format!("Round {}", round + 1),
+                        round as u8,
+                        [Slot::Team(team1), Slot::Team(team2)],
+                        format,
+                    ));
+                }
+            }
+            // Keep the first team fixed and rotate the rest
+            let last = rotation.pop();
+            if let Some(last) = last {
+                rotation.insert(1, last);
+            }
+        }
+
+        Self {
+            name,
+            region,
+            format: StageFormat::RoundRobin,
+            teams,
+            matches,
+            start_date: DateTime::UNIX_EPOCH,
+            days_between_rounds: 1,
+        }
+    }
+
+    /// A Swiss stage. Only the first round is drawn up front, top seed
+    /// against bottom seed; `pair_next_round` draws the rest as results come
+    /// in. In an odd field the middle seed gets a bye.
+    pub fn swiss(name: String, region: Option<String>, teams: Vec<Uuid>, format: SeriesFormat) -> Self {
+        let mut matches: Vec<BracketMatch> = (0..teams.len() / 2)
+            .map(|i| {
+                let slots = [Slot::Team(teams[i]), Slot::Team(teams[teams.len() - 1 - i])];
+                BracketMatch::new("Round 1".to_string(), 0, slots, format)
+            })
+            .collect();
+        if teams.len() % 2 == 1 {
+            let slots = [Slot::Team(teams[teams.len() / 2]), Slot::Bye];
+            matches.push(BracketMatch::new("Round 1".to_string(), 0, slots, format));
+        }
+
+        Self {
+            name,
+            region,
+            format: StageFormat::Swiss,
+            teams,
+            matches,
+            start_date: DateTime::UNIX_EPOCH,
+            days_between_rounds: 1,
+        }
+    }
+
+    /// A knockout bracket, 1 v N, 2 v N-1 and so on, with the final played as
+    /// `final_format`. Without a power-of-two field the top seeds get byes.
+    pub fn single_elimination(
+        name: String,
+        region: Option<String>,
+        teams: Vec<Uuid>,
+        format: SeriesFormat,
+        final_format: SeriesFormat,
+    ) -> Self {
+        let mut matches = Vec::new();
+        let mut previous: Vec<Slot> = Self::seeded_pairs(&teams).into_iter().flatten().collect();
+        let mut round = 0;
+
+        while previous.len() > 1 {
+            let is_final = previous.len() == 2;
+            let mut winners = Vec::new();
+            for (i, pair) in previous.chunks(2).enumerate() {
+                let label = match previous.len() {
+                    2 => "Grand Final".to_string(),
+                    4 => format!("Semifinal {}", i + 1),
+                    8 => format!("Quarterfinal {}", i + 1),
+                    _ => format!("Round {} Match {}", round + 1, i + 1),
+                };
+                let index = Self::push_match(&mut matches, label, [pair[0], pair[1]], if is_final { final_format } else { format });
+                winners.push(Slot::Winner(index));
+            }
+            previous = winners;
+            round += 1;
+        }
+
+        Self {
+            name,
+            region,
+            format: StageFormat::SingleElimination,
+            teams,
+            matches,
+            start_date: DateTime::UNIX_EPOCH,
+            days_between_rounds: 1,
+        }
+    }
+
+    /// A four-team GSL group: the opening winners meet for first place, the
+    /// opening losers play off to stay alive, and the Decider Match between
+    /// the Winners' Match loser and the Elimination Match winner settles
+    /// second place. Teams are in seed order, 1 v 4 and 2 v 3 to open.
+    pub fn gsl(name: String, region: Option<String>, teams: Vec<Uuid>, format: SeriesFormat) -> Self {
+        let mut matches = Vec::new();
+        let slot = |seed: usize| teams.get(seed).map_or(Slot::Bye, |&id| Slot::Team(id));
+        let opening_a = Self::push_match(&mut matches, "Opening Match A".to_string(), [slot(0), slot(3)], format);
+        let opening_b = Self::push_match(&mut matches, "Opening Match B".to_string(), [slot(1), slot(2)], format);
+        let winners = Self::push_match(
+            &mut matches,
+            "Winners' Match".to_string(),
+            [Slot::Winner(opening_a), Slot::Winner(opening_b)],
+            format,
+        );
+        let elimination = Self::push_match(
+            &mut matches,
+            "Elimination Match".to_string(),
+            [Slot::Loser(opening_a), Slot::Loser(opening_b)],
+            format,
+        );
+        Self::push_match(
+            &mut matches,
+            "Decider Match".to_string(),
+            [Slot::Loser(winners), Slot::Winner(elimination)],
+            format,
+        );
+
+        Self {
+            name,
+            region,
+            format: StageFormat::Gsl,
+            teams,
+            matches,
+            start_date: DateTime::UNIX_EPOCH,
+            days_between_rounds: 1,
+        }
+    }
+
+    /// A bracket where a team is out after its second loss. The upper
+    /// bracket winner meets the lower bracket winner in the Grand Final.
+    pub fn double_elimination(
+        name: String,
+        region: Option<String>,
+        teams: Vec<Uuid>,
+        format: SeriesFormat,
+        final_format: SeriesFormat,
+    ) -> Self {
+        let mut matches = Vec::new();
+        let (upper_winner, upper_drops) = Self::push_upper_bracket(&mut matches, &teams, format, format);
+        let (lower_winner, _) = Self::push_lower_bracket(&mut matches, "Lower", upper_drops, format);
+        Self::push_match(&mut matches, "Grand Final".to_string(), [upper_winner, lower_winner], final_format);
+
+        Self {
+            name,
+            region,
+            format: StageFormat::DoubleElimination,
+            teams,
+            matches,
+            start_date: DateTime::UNIX_EPOCH,
+            days_between_rounds: 1,
+        }
+    }
+
+    /// A bracket where a team is out after its third loss. Losing in the
+    /// upper bracket drops a team to the middle bracket, and losing there
+    /// drops it to the lower one. The upper bracket winner finishes first and
+    /// the middle and lower bracket winners play a Qualifier Final for second.
+    pub fn triple_elimination(
+        name: String,
+        region: Option<String>,
+        teams: Vec<Uuid>,
+        format: SeriesFormat,
+        final_format: SeriesFormat,
+    ) -> Self {
+        let mut matches = Vec::new();
+
+        // The upper bracket winner is done once it wins the Upper Final
+        let (_, upper_drops) = Self::push_upper_bracket(&mut matches, &teams, format, final_format);
+
+        let (middle_winner, middle_drops) = Self::push_lower_bracket(&mut matches, "Middle", upper_drops, format);
+        let (lower_winner, _) = Self::push_lower_bracket(&mut matches, "Lower", middle_drops, format);
+        Self::push_match(&mut matches, "Qualifier Final".to_string(), [middle_winner, lower_winner], final_format);
+
+        Self {
+            name,
+            region,
+            format: StageFormat::TripleElimination,
+            teams,
+            matches,
+            start_date: DateTime::UNIX_EPOCH,
+            days_between_rounds: 1,
+        }
+    }
+
+    /// Adds a match, played the round after the latest match it's waiting
+    /// on, and returns its index.
+    fn push_match(matches: &mut Vec<BracketMatch>, label: String, slots: [Slot; 2], format: SeriesFormat) -> usize {
+        let round = slots
+            .iter()
+            .filter_map(|slot| match slot {
+                Slot::Winner(index) | Slot::Loser(index) => matches.get(*index).map(|m| m.round + 1),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0);
+        matches.push(BracketMatch::new(label, round, slots, format));
+        matches.len() - 1
+    }
+
+    /// Adds a seeded knockout bracket with the Upper Final played as
+    /// `final_format`. Returns the winner's slot and the losers of each
+    /// round.
+    fn push_upper_bracket(
+        matches: &mut Vec<BracketMatch>,
+        teams: &[Uuid],
+        format: SeriesFormat,
+        final_format: SeriesFormat,
+    ) -> (Slot, Vec<Vec<Slot>>) {
+        let mut drops = Vec::new();
+        let mut survivors: Vec<Slot> = Self::seeded_pairs(teams).into_iter().flatten().collect();
+        let mut round = 1;
+        while survivors.len() > 1 {
+            let pairs = survivors.chunks(2).map(|pair| [pair[0], pair[1]]).collect();
+            let (label, round_format) = if survivors.len() == 2 {
+                ("Upper Final".to_string(), final_format)
+            } else {
+                (format!("Upper Round {}", round), format)
+            };
+            survivors = Self::push_round(matches, &label, pairs, round_format, &mut drops);
+            round += 1;
+        }
+        (survivors.first().copied().unwrap_or(Slot::Bye), drops)
+    }
+
+    /// Adds one round of matches and returns the winners' slots. The losers'
+    /// slots are added to `drops` as a group.
+    fn push_round(
+        matches: &mut Vec<BracketMatch>,
+        label: &str,
+        pairs: Vec<[Slot; 2]>,
+        format: SeriesFormat,
+        drops: &mut Vec<Vec<Slot>>,
+    ) -> Vec<Slot> {
+        let count = pairs.len();
+        let mut winners = Vec::new();
+        let mut losers = Vec::new();
+        for (i, slots) in pairs.into_iter().enumerate() {
+            let label = if count > 1 {
+                format!("{} Match {}", label, i + 1)
+            } else {
+                label.to_string()
+            };
+            let index = Self::push_match(matches, label, slots, format);
+            winners.push(Slot::Winner(index));
+            losers.push(Slot::Loser(index));
+        }
+        drops.push(losers);
+        winners
+    }
+
+    /// Adds a bracket fed by the losers of another one, `drops` holding them
+    /// round by round. Survivors play each other until there are as many of
+    /// them as teams dropping in, then face the new arrivals. Returns the
+    /// bracket winner's slot and this bracket's own losers, round by round.
+    fn push_lower_bracket(
+        matches: &mut Vec<BracketMatch>,
+        name: &str,
+        drops: Vec<Vec<Slot>>,
+        format: SeriesFormat,
+    ) -> (Slot, Vec<Vec<Slot>>) {
+        let mut own_drops = Vec::new();
+        let mut survivors: Vec<Slot> = Vec::new();
+        let mut round = 1;
+        let pair_up = |slots: &[Slot]| -> Vec<[Slot; 2]> { slots.chunks_exact(2).map(|pair| [pair[0], pair[1]]).collect() };
+
+        for mut group in drops {
+            if survivors.is_empty() {
+                survivors = group;
+                continue;
+            }
+            while survivors.len() > group.len() {
+                let label = format!("{} Round {}", name, round);
+                survivors = Self::push_round(matches, &label, pair_up(&survivors), format, &mut own_drops);
+                round += 1;
+            }
+            while group.len() > survivors.len() {
+                let label = format!("{} Round {}", name, round);
+                group = Self::push_round(matches, &label, pair_up(&group), format, &mut own_drops);
+                round += 1;
+            }
+            // New arrivals meet survivors from the far side of the bracket
+            let pairs = survivors.iter().zip(group.iter().rev()).map(|(&a, &b)| [a, b]).collect();
+            let label = format!("{} Round {}", name, round);
+            survivors = Self::push_round(matches, &label, pairs, format, &mut own_drops);
+            round += 1;
+        }
+        while survivors.len() > 1 {
+            let label = format!("{} Round {}", name, round);
+            survivors = Self::push_round(matches, &label, pair_up(&survivors), format, &mut own_drops);
+            round += 1;
+        }
+
+        if let Some(Slot::Winner(index)) = survivors.first() {
+            matches[*index].label = format!("{} Final", name);
+        }
+        (survivors.first().copied().unwrap_or(Slot::Bye), own_drops)
+    }
+
+    /// Sets when the first round is played and how many days apart the
+    /// rounds after it are.
+    pub fn starting(mut self, start_date: DateTime<Utc>, days_between_rounds: i64) -> Self {
+        self.start_date = start_date;
+        self.days_between_rounds = days_between_rounds;
+        self
+    }
+
+    pub fn round_date(&self, round: u8) -> DateTime<Utc> {
+        self.start_date + Duration::days(round as i64 * self.days_between_rounds)
+    }
+
+    /// First-round pairs for a bracket, ordered so the top two seeds can only
+    /// meet in the final. Seeds past the end of `teams` are byes.
+    fn seeded_pairs(teams: &[Uuid]) -> Vec<[Slot; 2]> {
+        let mut order = vec![0usize];
+        while order.len() < teams.len() {
+            let size = order.len() * 2;
+            order = order.into_iter().flat_map(|seed| [seed, size - 1 - seed]).collect();
+        }
+        let slot = |seed: usize| teams.get(seed).map_or(Slot::Bye, |&id| Slot::Team(id));
+        order
+            .chunks_exact(2)
+            .map(|pair| [slot(pair[0]), slot(pair[1])])
+            .collect()
+    }
+
+    /// Who ends up in a slot: `None` while it's still undecided, `Some(None)`
+    /// for a bye.
+    fn entrant(&self, slot: Slot) -> Option<Option<Uuid>> {
+        match slot {
+            Slot::Team(id) => Some(Some(id)),
+            Slot::Bye => Some(None),
+            Slot::Winner(index) => self.outcome(index).map(|(winner, _)| winner),
+            Slot::Loser(index) => self.outcome(index).map(|(_, loser)| loser),
+        }
+    }
+
+    /// Winner and loser of a match once it's decided. A match against a bye
+    /// is decided as soon as the other team is known.
+    fn outcome(&self, index: usize) -> Option<(Option<Uuid>, Option<Uuid>)> {
+        let bracket_match = self.matches.get(index)?;
+        if let Some(result) = &bracket_match.result {
+            return Some((Some(result.winner_id), Some(result.loser_id)));
+        }
+        let first = self.entrant(bracket_match.slots[0])?;
+        let second = self.entrant(bracket_match.slots[1])?;
+        match (first, second) {
+            (Some(_), Some(_)) => None,
+            (team, None) | (None, team) => Some((team, None)),
+        }
+    }
+
+    pub fn resolve(&self, slot: Slot) -> Option<Uuid> {
+        self.entrant(slot).flatten()
+    }
+
+    pub fn match_teams(&self, index: usize) -> Option<(Uuid, Uuid)> {
+        let slots = self.matches.get(index)?.slots;
+        Some((self.resolve(slots[0])?, self.resolve(slots[1])?))
+    }
+
+    /// Matches that can be played now but haven't been scheduled yet.
+    pub fn ready_matches(&self) -> Vec<usize> {
+        (0..self.matches.len())
+            .filter(|&i| {
+                let bracket_match = &self.matches[i];
+                bracket_match.fixture_id.is_none() && bracket_match.result.is_none() && self.match_teams(i).is_some()
+            })
+            .collect()
+    }
+
+    pub fn record_result(&mut self, index: usize, result: BracketResult) {
+        if let Some(bracket_match) = self.matches.get_mut(index) {
+            bracket_match.result = Some(result);
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        (0..self.matches.len()).all(|i| self.outcome(i).is_some())
+            && (self.format != StageFormat::Swiss || self.swiss_alive().len() < 2)
+    }
+
+    /// Teams in a Swiss stage that have neither advanced nor been knocked
+    /// out, in seed order.
+    fn swiss_alive(&self) -> Vec<Uuid> {
+        self.teams
+            .iter()
+            .copied()
+            .filter(|&id| {
+                let (wins, losses) = self.record(id);
+                wins < SWISS_WINS_TO_ADVANCE && losses < SWISS_LOSSES_TO_EXIT
+            })
+            .collect()
+    }
+
+    /// Teams through to the next stage of a Swiss event, best first.
+    pub fn swiss_advancing(&self) -> Vec<Uuid> {
+        self.placements()
+            .into_iter()
+            .filter(|&id| self.record(id).0 >= SWISS_WINS_TO_ADVANCE)
+            .collect()
+    }
+
+    /// Draws the next Swiss round once the last one is over: teams on the
+    /// same record meet, higher seeds against lower ones, without rematches
+    /// where that's possible. If an odd number are left, the lowest team
+    /// that hasn't had a bye yet sits the round out with a win. Returns true
+    /// if a round was added.
+    pub fn pair_next_round(&mut self) -> bool {
+        if self.format != StageFormat::Swiss || (0..self.matches.len()).any(|i| self.outcome(i).is_none()) {
+            return false;
+        }
+        let mut alive = self.swiss_alive();
+        if alive.len() < 2 {
+            return false;
+        }
+        alive.sort_by_key(|&id| {
+            let (wins, losses) = self.record(id);
+            (std::cmp::Reverse(wins), losses)
+        });
+        let bye = if alive.len() % 2 == 1 {
+            let index = alive.iter().rposition(|&id| self.byes(id) == 0).unwrap_or(alive.len() - 1);
+            Some(alive.remove(index))
+        } else {
+            None
+        };
+
+        let round = self.matches.last().map_or(0, |m| m.round + 1);
+        let format = self.matches.last().map_or(SeriesFormat::BestOf3, |m| m.format);
+        let mut pairs = Vec::new();
+        let mut carried: Vec<Uuid> = Vec::new();
+        let mut start = 0;
+        while start < alive.len() {
+            // A pool that can't be split evenly sends its lowest seed down
+            let record = self.record(alive[start]);
+            let end = alive[start..]
+                .iter()
+                .position(|&id| self.record(id) != record)
+                .map_or(alive.len(), |len| start + len);
+            let mut pool = std::mem::take(&mut carried);
+            pool.extend_from_slice(&alive[start..end]);
+            if pool.len() % 2 == 1 {
+                carried.extend(pool.pop());
+            }
+            pairs.extend(self.pair_without_rematches(&pool));
+            start = end;
+        }
+
+        for [team1, team2] in pairs {
+            let label = format!("Round {}", round + 1);
+            self.matches.push(BracketMatch::new(label, round, [Slot::Team(team1), Slot::Team(team2)], format));
+        }
+        if let Some(team) = bye {
+            let label = format!("Round {}", round + 1);
+            self.matches.push(BracketMatch::new(label, round, [Slot::Team(team), Slot::Bye], format));
+        }
+        true
+    }
+
+    /// Rounds a Swiss team has sat out.
+    fn byes(&self, team_id: Uuid) -> u32 {
+        self.matches.iter().filter(|m| m.slots == [Slot::Team(team_id), Slot::Bye]).count() as u32
+    }
+
+    fn have_played(&self, team1: Uuid, team2: Uuid) -> bool {
+        self.matches
+            .iter()
+            .any(|m| m.slots.contains(&Slot::Team(team1)) && m.slots.contains(&Slot::Team(team2)))
+    }
+
+    /// Pairs top against bottom, falling back to allowing rematches when
+    /// there's no other way to pair everyone.
+    fn pair_without_rematches(&self, teams: &[Uuid]) -> Vec<[Uuid; 2]> {
+        fn search(stage: &Stage, teams: &[Uuid]) -> Option<Vec<[Uuid; 2]>> {
+            let Some((&first, rest)) = teams.split_first() else {
+                return Some(Vec::new());
+            };
+            for i in (0..rest.len()).rev() {
+                if stage.have_played(first, rest[i]) {
+                    continue;
+                }
+                let mut remaining = rest.to_vec();
+                let opponent = remaining.remove(i);
+                if let Some(mut pairs) = search(stage, &remaining) {
+                    pairs.insert(0, [first, opponent]);
+                    return Some(pairs);
+                }
+            }
+            None
+        }
+
+        search(self, teams).unwrap_or_else(|| {
+            let (top, bottom) = teams.split_at(teams.len() / 2);
+            top.iter().zip(bottom.iter().rev()).map(|(&a, &b)| [a, b]).collect()
+        })
+    }
+
+    fn team_results(&self, team_id: Uuid) -> impl Iterator<Item = &BracketResult> {
+        self.matches
+            .iter()
+            .filter_map(|m| m.result.as_ref())
+            .filter(move |r| r.winner_id == team_id || r.loser_id == team_id)
+    }
+
+    /// Wins and losses. A Swiss bye counts as a win.
+    pub fn record(&self, team_id: Uuid) -> (u32, u32) {
+        let byes = if self.format == StageFormat::Swiss { self.byes(team_id) } else { 0 };
+        self.team_results(team_id).fold((byes, 0), |(wins, losses), r| {
+            if r.winner_id == team_id {
+                (wins + 1, losses)
+            } else {
+                (wins, losses + 1)
+            }
+        })
+    }
+
+    pub fn map_differential(&self, team_id: Uuid) -> i32 {
+        self.team_results(team_id)
+            .map(|r| {
+                let diff = r.winner_maps as i32 - r.loser_maps as i32;
+                if r.winner_id == team_id {
+                    diff
+                } else {
+                    -diff
+                }
+            })
+            .sum()
+    }
+
+    /// Final placings, best first. Groups and Swiss stages rank by record
+    /// then map difference.
+    /// Brackets put the winners of their deciding matches first, then rank
+    /// everyone else by how far they got.
+    pub fn placements(&self) -> Vec<Uuid> {
+        let mut teams = self.teams.clone();
+        if matches!(self.format, StageFormat::RoundRobin | StageFormat::Swiss) {
+            teams.sort_by_key(|&id| {
+                let (wins, losses) = self.record(id);
+                (std::cmp::Reverse(wins), losses, std::cmp::Reverse(self.map_differential(id)))
+            });
+            return teams;
+        }
+
+        // Deciding matches are the ones nobody advances from
+        let mut placed: Vec<Uuid> = (0..self.matches.len())
+            .filter(|&i| !self.matches.iter().any(|m| m.slots.contains(&Slot::Winner(i))))
+            .filter_map(|i| self.matches[i].result.as_ref().map(|r| r.winner_id))
+            .collect();
+        teams.retain(|id| !placed.contains(id));
+
+        // Later last match first, and a team that won its last match ahead
+        // of one that lost it
+        teams.sort_by_key(|&id| {
+            let last = self
+                .matches
+                .iter()
+                .rfind(|m| m.result.as_ref().is_some_and(|r| r.winner_id == id || r.loser_id == id));
+            let round = last.map(|m| m.round as i32).unwrap_or(-1);
+            let won = last.and_then(|m| m.result.as_ref()).is_some_and(|r| r.winner_id == id);
+            std::cmp::Reverse((round, won))
+        });
+        placed.extend(teams);
+        placed
+    }
+
+    pub fn winner(&self) -> Option<Uuid> {
+        if self.is_complete() {
+            self.placements().first().copied()
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn teams(count: u128) -> Vec<Uuid> {
+        (1..=count).map(Uuid::from_u128).collect()
+    }
+
+    /// Plays every match the stage offers, drawing Swiss rounds as they come
+    /// up. The better seed wins unless `upset` says otherwise for the match.
+    fn play_out(stage: &mut Stage, upset: impl Fn(usize) -> bool) {
+        loop {
+            let ready = stage.ready_matches();
+            if ready.is_empty() {
+                if stage.pair_next_round() {
+                    continue;
+                }
+                break;
+            }
+            for index in ready {
+                let (team1, team2) = stage.match_teams(index).unwrap();
+                let seed = |id: Uuid| stage.teams.iter().position(|&t| t == id).unwrap();
+                let (winner_id, loser_id) = if (seed(team1) < seed(team2)) != upset(index) {
+                    (team1, team2)
+                } else {
+                    (team2, team1)
+                };
+                stage.record_result(index, BracketResult { winner_id, loser_id, winner_maps: 2, loser_maps: 1 });
+            }
+        }
+    }
+
+    fn match_index(stage: &Stage, label: &str) -> usize {
+        stage.matches.iter().position(|m| m.label == label).unwrap()
+    }
+
+    #[test]
+    fn triple_elimination_takes_three_losses_to_knock_a_team_out() {
+        // Every third match goes to the worse seed
+        for offset in 0..3 {
+            let mut stage = Stage::triple_elimination(
+                "Kickoff".to_string(),
+                None,
+                teams(8),
+                SeriesFormat::BestOf3,
+                SeriesFormat::BestOf5,
+            );
+            play_out(&mut stage, |index| (index + offset) % 3 == 0);
+            assert!(stage.is_complete());
+
+            let upper_final = match_index(&stage, "Upper Final");
+            let qualifier_final = match_index(&stage, "Qualifier Final");
+            let first = stage.resolve(Slot::Winner(upper_final)).unwrap();
+            let second = stage.resolve(Slot::Winner(qualifier_final)).unwrap();
+            let runner_up = stage.resolve(Slot::Loser(qualifier_final)).unwrap();
+
+            assert_eq!(stage.placements()[..3], [first, second, runner_up]);
+            assert_eq!(stage.winner(), Some(first));
+            assert_eq!(stage.record(first).1, 0);
+            assert!(stage.record(second).1 <= 2);
+            assert!((2..=3).contains(&stage.record(runner_up).1));
+            for &team in &stage.teams {
+                if ![first, second, runner_up].contains(&team) {
+                    assert_eq!(stage.record(team).1, 3);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn kickoff_byes_go_through_and_finals_decide_qualifiers() {
+        for upsets in [false, true] {
+            let teams = teams(12);
+            let mut stage = Stage::triple_elimination(
+                "Kickoff".to_string(),
+                None,
+                teams.clone(),
+                SeriesFormat::BestOf3,
+                SeriesFormat::BestOf5,
+            );
+
+            // The top four seeds sit out the first round
+            let ready = stage.ready_matches();
+            for &seed in &teams[..4] {
+                let index = stage
+                    .matches
+                    .iter()
+                    .position(|m| m.slots.contains(&Slot::Team(seed)))
+                    .unwrap();
+                assert!(stage.matches[index].slots.contains(&Slot::Bye));
+                assert!(!ready.contains(&index));
+                assert_eq!(stage.resolve(Slot::Winner(index)), Some(seed));
+            }
+            assert_eq!(ready.len(), 4);
+
+            play_out(&mut stage, |_| upsets);
+            assert!(stage.is_complete());
+
+            let upper_final = match_index(&stage, "Upper Final");
+            let qualifier_final = match_index(&stage, "Qualifier Final");
+            let placements = stage.placements();
+            assert_eq!(placements.len(), 12);
+            assert_eq!(Some(placements[0]), stage.resolve(Slot::Winner(upper_final)));
+            assert_eq!(Some(placements[1]), stage.resolve(Slot::Winner(qualifier_final)));
+            assert_eq!(stage.winner(), Some(placements[0]));
+        }
+    }
+
+    #[test]
+    fn swiss_sends_four_of_eight_through_without_rematches() {
+        for upsets in [false, true] {
+            let mut stage = Stage::swiss("Swiss Stage".to_string(), None, teams(8), SeriesFormat::BestOf3);
+            play_out(&mut stage, |_| upsets);
+
+            assert!(stage.is_complete());
+            assert_eq!(stage.swiss_advancing().len(), 4);
+            assert!(stage.matches.iter().all(|m| m.round < 3));
+            for (i, first) in stage.matches.iter().enumerate() {
+                for second in &stage.matches[i + 1..] {
+                    assert!(!first.slots.iter().all(|slot| second.slots.contains(slot)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn odd_swiss_fields_give_byes_and_finish_every_team() {
+        for count in [5, 7, 9] {
+            for upsets in [false, true] {
+                let mut stage = Stage::swiss("Swiss Stage".to_string(), None, teams(count), SeriesFormat::BestOf3);
+                play_out(&mut stage, |_| upsets);
+
+                assert!(stage.is_complete());
+                for &team in &stage.teams {
+                    let (wins, losses) = stage.record(team);
+                    assert!(wins == SWISS_WINS_TO_ADVANCE || losses == SWISS_LOSSES_TO_EXIT, "{:?}", (wins, losses));
+                    assert!(stage.byes(team) <= 1);
+                }
+                for round in 0..=stage.matches.last().unwrap().round {
+                    let round_matches = stage.matches.iter().filter(|m| m.round == round);
+                    assert!(round_matches.filter(|m| m.slots.contains(&Slot::Bye)).count() <= 1);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn gsl_places_winners_match_then_decider() {
+        for upsets in [false, true] {
+            let mut stage = Stage::gsl("Group A".to_string(), None, teams(4), SeriesFormat::BestOf3);
+            play_out(&mut stage, |_| upsets);
+
+            assert!(stage.is_complete());
+            let winners = match_index(&stage, "Winners' Match");
+            let decider = match_index(&stage, "Decider Match");
+            let placements = stage.placements();
+            assert_eq!(Some(placements[0]), stage.resolve(Slot::Winner(winners)));
+            assert_eq!(Some(placements[1]), stage.resolve(Slot::Winner(decider)));
+            assert_eq!(Some(placements[2]), stage.resolve(Slot::Loser(decider)));
+            let elimination = match_index(&stage, "Elimination Match");
+            assert_eq!(Some(placements[3]), stage.resolve(Slot::Loser(elimination)));
+        }
+    }
+}
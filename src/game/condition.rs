@@ -0,0 +1,55 @@
+use rand::Rng;
+
+use crate::game::player::Player;
+
+/// Fatigue from flying out to an international event.
+pub const TRAVEL_FATIGUE: u8 = 15;
+
+/// Fatigue above which a player starts to risk burning out.
+const BURNOUT_THRESHOLD: u8 = 70;
+
+/// Fatigue a player sheds over a forced break each day, on top of rest.
+const BREAK_RECOVERY: u8 = 4;
+
+/// Fatigue from one map, lighter for players with more stamina.
+pub fn match_fatigue(player: &Player) -> u8 {
+    4 + 20u8.saturating_sub(player.attributes.stamina) / 3
+}
+
+/// A day's rest. Naturally fit players bounce back quicker, and a player on
+/// a break counts down towards their return.
+pub fn rest(player: &mut Player) {
+    let mut recovery = 2 + player.attributes.natural_fitness / 5;
+    if player.break_days > 0 {
+        player.break_days -= 1;
+        recovery += BREAK_RECOVERY;
+    }
+    player.fatigue = player.fatigue.saturating_sub(recovery);
+}
+
+pub fn add_fatigue(player: &mut Player, amount: u8) {
+    player.fatigue = player.fatigue.saturating_add(amount).min(100);
+}
+
+/// Rolls for burnout in a player who's been run into the ground. A burnt
+/// out player's morale takes a hit and they're sent on a break. Returns
+/// true if it happened.
+pub fn check_burnout<R: Rng + ?Sized>(player: &mut Player, rng: &mut R) -> bool {
+    if player.break_days > 0 || player.fatigue <= BURNOUT_THRESHOLD {
+        return false;
+    }
+    let chance = (player.fatigue - BURNOUT_THRESHOLD) as f64 / 300.0;
+    if !rng.gen_bool(chance) {
+        return false;
+    }
+    player.decrease_morale();
+    player.decrease_morale();
+    player.break_days = rng.gen_range(7..=14);
+    true
+}
+
+/// How much of their ability a player brings to a match. Tiredness only
+/// starts to show past 30% fatigue, and costs up to 15% when exhausted.
+pub fn condition_modifier(fatigue: u8) -> f32 {
+    1.0 - fatigue.saturating_sub(30) as f32 / 70.0 * 0.15
+}
@@ -0,0 +1,133 @@
+use serde::{Deserialize, Serialize};
+use rand::Rng;
+use uuid::Uuid;
+
+use crate::game::player::{Morale, Player};
+use crate::game::transfer::TransferBid;
+
+/// Offers this far below what the player wants are an insult; their agent
+/// ends talks on the spot.
+const INSULT_THRESHOLD: f64 = 0.6;
+
+/// Offers at least this close to the demand get a counter-offer rather than
+/// a flat rejection.
+const COUNTER_THRESHOLD: f64 = 0.85;
+
+/// Contract terms, either as asked for by a player or as offered by the
+/// manager.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContractOffer {
+    pub salary: i64, // Per year
+    pub length: u8,  // Years
+    pub starter_guarantee: bool,
+    pub signing_bonus: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NegotiationResponse {
+    Accepted,
+    Countered(ContractOffer),
+    Rejected,
+    WalkedAway,
+}
+
+/// Talks between the manager and a player's agent over new terms: a
+/// renewal, personal terms for a transfer, or a free agent's first deal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Negotiation {
+    pub player_id: Uuid,
+    pub demand: ContractOffer,
+    pub patience: u8, // Offers the agent will hear before walking away
+    pub transfer: Option<TransferBid>, // None for a renewal or a free agent
+    pub free_agent: bool,
+}
+
+impl Negotiation {
+    /// The agent's opening demands. Players ask for more when they're
+    /// unhappy or the team is losing, and less to stay with a winning side.
+    /// Veterans want security; established starters want a guaranteed
+    /// place.
+    pub fn open<R: Rng + ?Sized>(player: &Player, is_starter: bool, team_win_rate: f32, rng: &mut R) -> Self {
+        let morale_factor = match player.morale {
+            Morale::Abysmal => 1.3,
+            Morale::Poor => 1.15,
+            Morale::Average => 1.0,
+            Morale::Good => 0.95,
+            Morale::Superb => 0.9,
+        };
+        let success_factor = 1.1 - team_win_rate as f64 * 0.2;
+        let base_salary = player.market_value as f64 / 5.0;
+        let salary = base_salary * morale_factor * success_factor * rng.gen_range(0.95..1.1);
+
+        let length = match player.age {
+            0..=22 => 2,
+            23..=27 => rng.gen_range(2..=3),
+            _ => 3,
+        };
+        let bonus_share = if (player.morale as u8) < Morale::Average as u8 { 0.2 } else { 0.1 };
+
+        Self {
+            player_id: player.id,
+            demand: ContractOffer {
+                salary: round_to_thousand(salary),
+                length,
+                starter_guarantee: is_starter,
+                signing_bonus: round_to_thousand(salary * bonus_share),
+            },
+            patience: match player.morale {
+                Morale::Abysmal | Morale::Poor => 2,
+                Morale::Average => 3,
+                Morale::Good | Morale::Superb => 4,
+            },
+            transfer: None,
+            free_agent: false,
+        }
+    }
+
+    /// Whether the player is already on the user's books.
+    pub fn is_renewal(&self) -> bool {
+        self.transfer.is_none() && !self.free_agent
+    }
+
+    /// How an offer compares to the demand, where 1.0 or more is enough.
+    /// Salary matters most; a different length, a missing guarantee or a
+    /// smaller bonus each take something off.
+    pub fn satisfaction(&self, offer: &ContractOffer) -> f64 {
+        let demand = &self.demand;
+        let salary = offer.salary as f64 / demand.salary.max(1) as f64;
+        let length = offer.length.abs_diff(demand.length) as f64 * 0.03;
+        let guarantee = if demand.starter_guarantee && !offer.starter_guarantee { 0.1 } else { 0.0 };
+        let bonus = (offer.signing_bonus - demand.signing_bonus) as f64 / demand.salary.max(1) as f64;
+        salary - length - guarantee + bonus.min(0.2)
+    }
+
+    /// The agent's answer to an offer. Close offers are met halfway with a
+    /// counter; each offer that falls short wears down their patience.
+    pub fn respond(&mut self, offer: &ContractOffer) -> NegotiationResponse {
+        let satisfaction = self.satisfaction(offer);
+        if satisfaction >= 1.0 {
+            return NegotiationResponse::Accepted;
+        }
+        if satisfaction < INSULT_THRESHOLD {
+            self.patience = 0;
+            return NegotiationResponse::WalkedAway;
+        }
+
+        self.patience = self.patience.saturating_sub(1);
+        if self.patience == 0 {
+            return NegotiationResponse::WalkedAway;
+        }
+        if satisfaction < COUNTER_THRESHOLD {
+            return NegotiationResponse::Rejected;
+        }
+
+        // Meet the manager halfway on money, but hold firm on the rest
+        self.demand.salary = round_to_thousand((self.demand.salary + offer.salary) as f64 / 2.0);
+        self.demand.signing_bonus = round_to_thousand((self.demand.signing_bonus + offer.signing_bonus.max(0)) as f64 / 2.0);
+        NegotiationResponse::Countered(self.demand.clone())
+    }
+}
+
+pub(crate) fn round_to_thousand(amount: f64) -> i64 {
+    (amount / 1000.0).round() as i64 * 1000
+}
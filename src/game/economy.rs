@@ -8,6 +8,14 @@ pub enum BuyPhase {
     ForceBuy,
 }
 
+/// How a whole team spent its money in a single round.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamRoundBuy {
+    pub buy_phase: BuyPhase,
+    pub credits_spent: u32,
+    pub credits_remaining: u32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TeamFinances {
     pub budget: i64,
@@ -69,6 +77,12 @@ pub struct RoundEconomy {
     pub armor_cost: u32,
 }
 
+impl Default for RoundEconomy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl RoundEconomy {
     pub fn new() -> Self {
         Self {
@@ -116,9 +130,12 @@ impl RoundEconomy {
         }
 
         let total_cost = self.weapons_cost + self.armor_cost + self.utility_cost;
-        if total_cost <= self.credits {
-            self.credits -= total_cost;
+        if total_cost > self.credits {
+            // Can't afford the planned buy, so spend whatever is left instead
+            self.execute_buy(BuyPhase::ForceBuy);
+            return;
         }
+        self.credits -= total_cost;
         
         self.buy_phase = buy_phase;
     }
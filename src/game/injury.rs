@@ -0,0 +1,169 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Duration, Utc};
+use rand::Rng;
+
+use crate::game::player::Player;
+
+/// Daily chance of a strain for a rested player of average fitness.
+const BASE_INJURY_CHANCE: f64 = 0.0002;
+
+/// Daily chance of picking up an illness, before workload.
+const BASE_ILLNESS_CHANCE: f64 = 0.0001;
+
+/// Days after a return during which an injury can flare up again.
+const RELAPSE_WINDOW_DAYS: i64 = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum InjuryKind {
+    Wrist,
+    Back,
+    Illness,
+}
+
+impl InjuryKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            InjuryKind::Wrist => "Wrist strain (RSI)",
+            InjuryKind::Back => "Back injury",
+            InjuryKind::Illness => "Illness",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum InjurySeverity {
+    Minor,
+    Moderate,
+    Severe,
+}
+
+impl InjurySeverity {
+    pub fn name(&self) -> &'static str {
+        match self {
+            InjurySeverity::Minor => "Minor",
+            InjurySeverity::Moderate => "Moderate",
+            InjurySeverity::Severe => "Severe",
+        }
+    }
+
+    fn worse(&self) -> Self {
+        match self {
+            InjurySeverity::Minor => InjurySeverity::Moderate,
+            _ => InjurySeverity::Severe,
+        }
+    }
+
+    /// Days out for a player of average fitness.
+    fn days_out<R: Rng + ?Sized>(&self, kind: InjuryKind, rng: &mut R) -> i64 {
+        match (kind, self) {
+            (InjuryKind::Illness, InjurySeverity::Minor) => rng.gen_range(2..=5),
+            (InjuryKind::Illness, _) => rng.gen_range(6..=12),
+            (_, InjurySeverity::Minor) => rng.gen_range(3..=7),
+            (_, InjurySeverity::Moderate) => rng.gen_range(8..=21),
+            (_, InjurySeverity::Severe) => rng.gen_range(22..=56),
+        }
+    }
+
+    /// Daily chance of the injury flaring up again once the player is back.
+    fn relapse_risk(&self, kind: InjuryKind) -> f64 {
+        match (kind, self) {
+            (InjuryKind::Illness, _) => 0.001,
+            (_, InjurySeverity::Minor) => 0.002,
+            (_, InjurySeverity::Moderate) => 0.005,
+            (_, InjurySeverity::Severe) => 0.01,
+        }
+    }
+}
+
+/// An injury or illness keeping a player out, or one they've recently come
+/// back from and could still aggravate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Injury {
+    pub kind: InjuryKind,
+    pub severity: InjurySeverity,
+    pub return_date: DateTime<Utc>,
+    pub relapse_risk: f64, // Daily chance once back, before fitness and workload
+    pub recovered: bool,
+}
+
+impl Injury {
+    pub fn new<R: Rng + ?Sized>(
+        kind: InjuryKind,
+        severity: InjurySeverity,
+        date: DateTime<Utc>,
+        natural_fitness: u8,
+        rng: &mut R,
+    ) -> Self {
+        // Fitter players heal quicker
+        let days = severity.days_out(kind, rng) as f64 * (1.2 - natural_fitness as f64 / 50.0);
+        Self {
+            kind,
+            severity,
+            return_date: date + Duration::days(days.round().max(1.0) as i64),
+            relapse_risk: severity.relapse_risk(kind),
+            recovered: false,
+        }
+    }
+
+    /// Whether the player is back but still inside the relapse window.
+    pub fn can_relapse(&self, date: DateTime<Utc>) -> bool {
+        self.recovered && date < self.return_date + Duration::days(RELAPSE_WINDOW_DAYS)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InjuryEvent {
+    Injured,
+    Relapsed,
+    Recovered,
+}
+
+/// A day in the life of a player's body: injured players heal, recent
+/// returners may relapse and everyone else risks a new injury or illness.
+/// Poor natural fitness and a heavy workload make both more likely.
+pub fn check_injury<R: Rng + ?Sized>(player: &mut Player, date: DateTime<Utc>, rng: &mut R) -> Option<InjuryEvent> {
+    let frailty = 1.5 - player.attributes.natural_fitness as f64 / 20.0;
+    let workload = 1.0 + player.fatigue as f64 / 25.0;
+    let natural_fitness = player.attributes.natural_fitness;
+
+    match &mut player.injury {
+        Some(injury) if !injury.recovered => {
+            if date < injury.return_date {
+                return None;
+            }
+            injury.recovered = true;
+            return Some(InjuryEvent::Recovered);
+        }
+        Some(injury) if injury.can_relapse(date) => {
+            if !rng.gen_bool((injury.relapse_risk * frailty * workload).min(1.0)) {
+                return None;
+            }
+            let relapse = Injury::new(injury.kind, injury.severity.worse(), date, natural_fitness, rng);
+            player.injury = Some(relapse);
+            return Some(InjuryEvent::Relapsed);
+        }
+        _ => {}
+    }
+
+    let (kind, severity) = if rng.gen_bool(BASE_INJURY_CHANCE * frailty * workload) {
+        let kind = if rng.gen_bool(0.5) { InjuryKind::Wrist } else { InjuryKind::Back };
+        let severity = match rng.gen_range(0..10) {
+            0..=5 => InjurySeverity::Minor,
+            6..=8 => InjurySeverity::Moderate,
+            _ => InjurySeverity::Severe,
+        };
+        (kind, severity)
+    } else if rng.gen_bool(BASE_ILLNESS_CHANCE * workload) {
+        let severity = if rng.gen_bool(0.75) { InjurySeverity::Minor } else { InjurySeverity::Moderate };
+        (InjuryKind::Illness, severity)
+    } else {
+        // Long enough clear of the last injury for it to be forgotten
+        if player.injury.is_some() {
+            player.injury = None;
+        }
+        return None;
+    };
+
+    player.injury = Some(Injury::new(kind, severity, date, natural_fitness, rng));
+    Some(InjuryEvent::Injured)
+}
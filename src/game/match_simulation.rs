@@ -1,6 +1,32 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
+use rand::Rng;
+
+use crate::game::agent::{AbilityType, Agent};
+use crate::game::condition::condition_modifier;
+use crate::game::economy::{BuyPhase, RoundEconomy, TeamRoundBuy};
+use crate::game::player::{Morale, Player};
+use crate::game::rng::random_uuid;
+use crate::game::tactics::{assign_agents, tactical_matchup, EconomicPolicy, MapTactics};
+use crate::game::team::{Team, TeamComposition};
+
+const ROUNDS_TO_WIN: u8 = 13;
+const ROUNDS_PER_HALF: u8 = 12;
+const OVERTIME_CREDITS: u32 = 5000;
+
+/// How much holding angles helps the defenders before the spike goes down.
+/// Split and Ascent are the classic defender-sided maps.
+pub fn map_defense_bias(map: &str) -> f32 {
+    match map {
+        "Split" => 1.08,
+        "Ascent" => 1.07,
+        "Bind" | "Haven" | "Lotus" => 1.05,
+        "Icebox" | "Breeze" => 1.03,
+        "Fracture" => 1.01,
+        _ => 1.05,
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchResult {
@@ -13,6 +39,8 @@ pub struct MatchResult {
     pub map: String,
     pub winner_id: Option<Uuid>,
     pub match_type: MatchType,
+    pub rounds: Vec<RoundResult>,
+    pub player_stats: Vec<PlayerMatchStats>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,14 +52,42 @@ pub enum MatchType {
     Champions,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Side {
+    Attack,
+    Defense,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum RoundWinCondition {
+    Elimination,
+    SpikeDetonated,
+    SpikeDefused,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoundResult {
+    pub round_number: u8,
+    pub winner_id: Uuid,
+    pub team1_side: Side,
+    pub win_condition: RoundWinCondition,
+    pub spike_planted: bool,
+    pub first_kill_player_id: Option<Uuid>,
+    pub team1_buy: TeamRoundBuy,
+    pub team2_buy: TeamRoundBuy,
+}
+
 impl MatchResult {
-    pub fn new(
+    /// The date starts at the Unix epoch; whoever schedules the match sets it
+    /// to the in-game date.
+    pub fn new<R: Rng + ?Sized>(
         team1_id: Uuid,
         team2_id: Uuid,
         team1_score: u8,
         team2_score: u8,
         map: String,
         match_type: MatchType,
+        rng: &mut R,
     ) -> Self {
         let winner_id = if team1_score > team2_score {
             Some(team1_id)
@@ -42,8 +98,8 @@ impl MatchResult {
         };
 
         Self {
-            id: Uuid::new_v4(),
-            date: Utc::now(),
+            id: random_uuid(rng),
+            date: DateTime::UNIX_EPOCH,
             team1_id,
             team2_id,
             team1_score,
@@ -51,8 +107,25 @@ impl MatchResult {
             map,
             winner_id,
             match_type,
+            rounds: Vec::new(),
+            player_stats: Vec::new(),
         }
     }
+
+    fn team_buys(&self, team_id: Uuid) -> impl Iterator<Item = &TeamRoundBuy> {
+        let is_team1 = team_id == self.team1_id;
+        self.rounds
+            .iter()
+            .map(move |r| if is_team1 { &r.team1_buy } else { &r.team2_buy })
+    }
+
+    pub fn credits_spent(&self, team_id: Uuid) -> u32 {
+        self.team_buys(team_id).map(|b| b.credits_spent).sum()
+    }
+
+    pub fn buy_count(&self, team_id: Uuid, buy_phase: BuyPhase) -> usize {
+        self.team_buys(team_id).filter(|b| b.buy_phase == buy_phase).count()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -95,4 +168,638 @@ impl PlayerMatchStats {
         // Simplified Average Combat Score calculation
         (self.kills as f32 * 150.0 + self.assists as f32 * 50.0 + self.damage as f32 * 0.15) / 13.0
     }
-} 
\ No newline at end of file
+}
+
+/// A player taking part in a simulated map, with the running state the
+/// simulator needs between rounds.
+struct SimPlayer<'a> {
+    player: &'a Player,
+    agent: Option<Agent>,
+    stats: PlayerMatchStats,
+    headshots: u8,
+    alive: bool,
+    economy: RoundEconomy,
+    round_kills: u8,
+}
+
+/// Plays a single map between two teams round by round, first to 13 with
+/// win-by-two overtime.
+pub struct MatchSimulator<'a> {
+    teams: [&'a Team; 2],
+    lineups: [Vec<&'a Player>; 2],
+    tactics: [MapTactics; 2],
+    agents: [Vec<(&'a Player, Agent)>; 2],
+    map: String,
+    match_type: MatchType,
+    team1_starting_side: Side,
+}
+
+impl<'a> MatchSimulator<'a> {
+    pub fn new(
+        team1: &'a Team,
+        team2: &'a Team,
+        players: &'a [Player],
+        map: String,
+        match_type: MatchType,
+    ) -> Self {
+        let lineup = |team: &Team| -> Vec<&'a Player> {
+            // Anyone unavailable is covered from the bench
+            let available = |id: &Uuid| players.iter().find(|p| p.id == *id && p.is_available());
+            let mut lineup: Vec<&'a Player> = team.starting_lineup.iter().filter_map(available).collect();
+            let bench = team.players.iter().filter(|id| !team.starting_lineup.contains(id));
+            lineup.extend(bench.filter_map(available).take(5usize.saturating_sub(lineup.len())));
+            lineup
+        };
+
+        let lineups = [lineup(team1), lineup(team2)];
+        let tactics = [team1.tactics.get_map_tactics(&map), team2.tactics.get_map_tactics(&map)];
+        let agents = [
+            assign_agents(&lineups[0], &tactics[0].composition),
+            assign_agents(&lineups[1], &tactics[1].composition),
+        ];
+
+        Self {
+            teams: [team1, team2],
+            lineups,
+            tactics,
+            agents,
+            map,
+            match_type,
+            team1_starting_side: Side::Attack,
+        }
+    }
+
+    pub fn with_team1_starting_side(mut self, side: Side) -> Self {
+        self.team1_starting_side = side;
+        self
+    }
+
+    pub fn simulate<R: Rng + ?Sized>(&self, rng: &mut R) -> MatchResult {
+        let mut sides: [Vec<SimPlayer>; 2] = [0, 1].map(|t| {
+            self.lineups[t]
+                .iter()
+                .map(|&player| SimPlayer {
+                    player,
+                    agent: self.agents[t]
+                        .iter()
+                        .find(|(p, _)| p.id == player.id)
+                        .map(|&(_, agent)| agent),
+                    stats: PlayerMatchStats::new(player.id),
+                    headshots: 0,
+                    alive: true,
+                    economy: RoundEconomy::new(),
+                    round_kills: 0,
+                })
+                .collect()
+        });
+
+        let mut score = [0u8; 2];
+        let mut loss_streak = [0u8; 2];
+        let mut rounds = Vec::new();
+        let mut round_number = 0u8;
+
+        while !Self::is_finished(score) {
+            round_number += 1;
+            let attacker = self.attacking_team(round_number);
+            let pressure = score[0] >= ROUNDS_TO_WIN - 1 || score[1] >= ROUNDS_TO_WIN - 1;
+
+            // Both pistol rounds start from scratch, and overtime hands everyone a full buy
+            if round_number == 1 || round_number == ROUNDS_PER_HALF + 1 {
+                for sim in sides.iter_mut().flatten() {
+                    sim.economy = RoundEconomy::new();
+                }
+                loss_streak = [0, 0];
+            } else if round_number > ROUNDS_PER_HALF * 2 {
+                for sim in sides.iter_mut().flatten() {
+                    sim.economy.credits = OVERTIME_CREDITS;
+                }
+            }
+
+            let buys = [0, 1].map(|team| {
+                let must_force = Self::is_pistol_round(round_number)
+                    || round_number == ROUNDS_PER_HALF
+                    || round_number == ROUNDS_PER_HALF * 2
+                    || score[1 - team] == ROUNDS_TO_WIN - 1;
+                Self::execute_team_buy(&mut sides[team], must_force, self.tactics[team].economic_policy)
+            });
+
+            let round = self.simulate_round(rng, &mut sides, round_number, attacker, pressure, buys);
+            let winner = if round.winner_id == self.teams[0].id { 0 } else { 1 };
+            score[winner] += 1;
+
+            loss_streak[winner] = 0;
+            loss_streak[1 - winner] += 1;
+            Self::award_credits(&mut sides, winner, attacker, loss_streak[1 - winner], round.spike_planted);
+            rounds.push(round);
+        }
+
+        let mut result = MatchResult::new(
+            self.teams[0].id,
+            self.teams[1].id,
+            score[0],
+            score[1],
+            self.map.clone(),
+            self.match_type.clone(),
+            rng,
+        );
+        result.rounds = rounds;
+        result.player_stats = sides
+            .into_iter()
+            .flatten()
+            .map(|mut sim| {
+                if sim.stats.kills > 0 {
+                    sim.stats.headshot_percentage =
+                        sim.headshots as f32 / sim.stats.kills as f32 * 100.0;
+                }
+                sim.stats
+            })
+            .collect();
+        result
+    }
+
+    fn is_finished(score: [u8; 2]) -> bool {
+        let leader = score[0].max(score[1]);
+        let trailer = score[0].min(score[1]);
+        leader >= ROUNDS_TO_WIN && leader - trailer >= 2
+    }
+
+    fn is_pistol_round(round_number: u8) -> bool {
+        round_number == 1 || round_number == ROUNDS_PER_HALF + 1
+    }
+
+    /// The team saves together when its economic policy says the lineup can't
+    /// afford a real buy, unless there is nothing left to save for. Everyone
+    /// else buys what their own credits allow.
+    fn execute_team_buy(side: &mut [SimPlayer], must_force: bool, policy: EconomicPolicy) -> TeamRoundBuy {
+        let banked: u32 = side.iter().map(|s| s.economy.credits).sum();
+
+        for sim in side.iter_mut() {
+            sim.economy.determine_buy_phase();
+        }
+        let count = |phases: &[BuyPhase]| side.iter().filter(|s| phases.contains(&s.economy.buy_phase)).count();
+        let team_saves = !must_force
+            && match policy {
+                EconomicPolicy::Conservative => count(&[BuyPhase::EcoRound]) * 2 > side.len(),
+                EconomicPolicy::AggressiveForceBuys => count(&[BuyPhase::EcoRound]) == side.len(),
+                EconomicPolicy::SaveForFullBuys => count(&[BuyPhase::EcoRound, BuyPhase::HalfBuy]) * 2 > side.len(),
+            };
+        let force = must_force || policy == EconomicPolicy::AggressiveForceBuys;
+
+        for sim in side.iter_mut() {
+            let buy_phase = if team_saves {
+                BuyPhase::EcoRound
+            } else if force && sim.economy.buy_phase != BuyPhase::FullBuy {
+                BuyPhase::ForceBuy
+            } else {
+                sim.economy.buy_phase
+            };
+            sim.economy.execute_buy(buy_phase);
+        }
+
+        let remaining: u32 = side.iter().map(|s| s.economy.credits).sum();
+        TeamRoundBuy {
+            buy_phase: Self::dominant_buy_phase(side),
+            credits_spent: banked - remaining,
+            credits_remaining: remaining,
+        }
+    }
+
+    fn dominant_buy_phase(side: &[SimPlayer]) -> BuyPhase {
+        [BuyPhase::FullBuy, BuyPhase::ForceBuy, BuyPhase::HalfBuy, BuyPhase::EcoRound]
+            .into_iter()
+            .max_by_key(|phase| side.iter().filter(|s| s.economy.buy_phase == *phase).count())
+            .unwrap_or(BuyPhase::EcoRound)
+    }
+
+    fn award_credits(
+        sides: &mut [Vec<SimPlayer>; 2],
+        winner: usize,
+        attacker: usize,
+        loser_streak: u8,
+        spike_planted: bool,
+    ) {
+        for (team, side) in sides.iter_mut().enumerate() {
+            for sim in side.iter_mut() {
+                if team == winner {
+                    sim.economy.round_win_bonus();
+                } else {
+                    sim.economy.round_loss_bonus(loser_streak);
+                }
+                sim.economy.kill_bonus(sim.round_kills);
+                if spike_planted && team == attacker {
+                    sim.economy.spike_plant_bonus();
+                }
+            }
+        }
+    }
+
+    /// The team that starts on attack attacks the first half, the other team
+    /// the second, and sides swap every round in overtime.
+    fn attacking_team(&self, round_number: u8) -> usize {
+        let first = match self.team1_starting_side {
+            Side::Attack => 0,
+            Side::Defense => 1,
+        };
+        if round_number <= ROUNDS_PER_HALF {
+            first
+        } else if round_number <= ROUNDS_PER_HALF * 2 {
+            1 - first
+        } else {
+            (first + (round_number - ROUNDS_PER_HALF * 2 - 1) as usize) % 2
+        }
+    }
+
+    fn simulate_round<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        sides: &mut [Vec<SimPlayer>; 2],
+        round_number: u8,
+        attacker: usize,
+        pressure: bool,
+        buys: [TeamRoundBuy; 2],
+    ) -> RoundResult {
+        let defender = 1 - attacker;
+        for sim in sides.iter_mut().flatten() {
+            sim.alive = true;
+            sim.round_kills = 0;
+        }
+
+        let utility = [self.utility_modifier(&sides[0], 0), self.utility_modifier(&sides[1], 1)];
+        let mut spike_planted = false;
+        let mut first_kill_player_id = None;
+        let mut clutcher: [Option<usize>; 2] = [None, None];
+
+        let winner = loop {
+            let alive = [Self::alive_count(&sides[0]), Self::alive_count(&sides[1])];
+
+            if alive[defender] == 0 {
+                break (attacker, RoundWinCondition::Elimination);
+            }
+            if alive[attacker] == 0 {
+                if !spike_planted {
+                    break (defender, RoundWinCondition::Elimination);
+                }
+                // Defenders still have to get the defuse off after the last kill
+                let defuse_chance = 0.65 + 0.05 * alive[defender].min(4) as f64;
+                if rng.gen_bool(defuse_chance) {
+                    break (defender, RoundWinCondition::SpikeDefused);
+                }
+                break (attacker, RoundWinCondition::SpikeDetonated);
+            }
+
+            for team in 0..2 {
+                if alive[team] == 1 && clutcher[team].is_none() {
+                    if let Some(index) = sides[team].iter().position(|s| s.alive) {
+                        sides[team][index].stats.clutches_attempted += 1;
+                        clutcher[team] = Some(index);
+                    }
+                }
+            }
+
+            if !spike_planted && alive[attacker] >= alive[defender] {
+                let game_sense = Self::average_alive(&sides[attacker], |p| p.attributes.game_sense);
+                let mut plant_chance = 0.25 + game_sense / 100.0 + self.tactics[attacker].attack_style.plant_modifier();
+                if Self::has_ability_alive(&sides[attacker], AbilityType::Smoke) {
+                    plant_chance += 0.05;
+                }
+                if rng.gen_bool(plant_chance.clamp(0.05, 0.75)) {
+                    spike_planted = true;
+                }
+            }
+
+            let a = Self::pick_duelist(rng, &sides[attacker]);
+            let d = Self::pick_duelist(rng, &sides[defender]);
+
+            let mut attack_rating = self.duel_rating(attacker, &sides[attacker][a], pressure, clutcher[attacker].is_some())
+                * utility[attacker];
+            let mut defense_rating = self.duel_rating(defender, &sides[defender][d], pressure, clutcher[defender].is_some())
+                * utility[defender];
+            attack_rating *= tactical_matchup(self.tactics[attacker].attack_style, self.tactics[defender].defense_style);
+            // Defenders hold the angles until the spike is down, then the roles flip
+            if spike_planted {
+                attack_rating *= 1.1;
+                // Post-plant lineups make the retake harder
+                if Self::has_ability_alive(&sides[attacker], AbilityType::Molly) {
+                    attack_rating *= 1.03;
+                }
+            } else {
+                defense_rating *= map_defense_bias(&self.map);
+                // Traps watch the flank and slow the push down
+                if Self::has_ability_alive(&sides[defender], AbilityType::Trap) {
+                    defense_rating *= 1.03;
+                }
+            }
+            // Flashes and recon help win the opening duel
+            if first_kill_player_id.is_none()
+                && (Self::has_ability_alive(&sides[attacker], AbilityType::Flash)
+                    || Self::has_ability_alive(&sides[attacker], AbilityType::Recon))
+            {
+                attack_rating *= 1.05;
+            }
+
+            // Straight odds: sharpening them lets the best players go 2+ K/D
+            let attacker_wins = rng.gen_bool((attack_rating / (attack_rating + defense_rating)) as f64);
+
+            let (killer_team, killer, victim_team, victim) = if attacker_wins {
+                (attacker, a, defender, d)
+            } else {
+                (defender, d, attacker, a)
+            };
+
+            if first_kill_player_id.is_none() {
+                first_kill_player_id = Some(sides[killer_team][killer].player.id);
+                sides[killer_team][killer].stats.first_kills += 1;
+            }
+
+            Self::resolve_kill(rng, sides, killer_team, killer, victim_team, victim);
+        };
+
+        let (winning_team, win_condition) = winner;
+        let [team1_buy, team2_buy] = buys;
+        if let Some(index) = clutcher[winning_team] {
+            sides[winning_team][index].stats.clutches_won += 1;
+        }
+
+        RoundResult {
+            round_number,
+            winner_id: self.teams[winning_team].id,
+            team1_side: if attacker == 0 { Side::Attack } else { Side::Defense },
+            win_condition,
+            spike_planted,
+            first_kill_player_id,
+            team1_buy,
+            team2_buy,
+        }
+    }
+
+    fn resolve_kill<R: Rng + ?Sized>(
+        rng: &mut R,
+        sides: &mut [Vec<SimPlayer>; 2],
+        killer_team: usize,
+        killer: usize,
+        victim_team: usize,
+        victim: usize,
+    ) {
+        let headshot_chance = 0.1 + sides[killer_team][killer].player.attributes.aim as f64 / 20.0 * 0.25;
+        let chip_damage = rng.gen_range(0..=90);
+
+        let killer_sim = &mut sides[killer_team][killer];
+        killer_sim.stats.kills = killer_sim.stats.kills.saturating_add(1);
+        killer_sim.round_kills += 1;
+        killer_sim.stats.damage += rng.gen_range(130..=160);
+        if rng.gen_bool(headshot_chance) {
+            killer_sim.headshots = killer_sim.headshots.saturating_add(1);
+        }
+
+        let victim_sim = &mut sides[victim_team][victim];
+        victim_sim.alive = false;
+        victim_sim.stats.deaths = victim_sim.stats.deaths.saturating_add(1);
+        victim_sim.stats.damage += chip_damage;
+
+        // Teammates who flashed, revealed or softened the target get the assist
+        let mut assists = 0;
+        for (index, teammate) in sides[killer_team].iter_mut().enumerate() {
+            if index == killer || !teammate.alive || assists >= 2 {
+                continue;
+            }
+            if rng.gen_bool(teammate.player.attributes.utility_usage as f64 / 70.0) {
+                teammate.stats.assists = teammate.stats.assists.saturating_add(1);
+                teammate.stats.damage += rng.gen_range(20..=60);
+                assists += 1;
+            }
+        }
+    }
+
+    /// Aggressive players are more likely to be the ones taking the next fight.
+    fn pick_duelist<R: Rng + ?Sized>(rng: &mut R, side: &[SimPlayer]) -> usize {
+        let total: u32 = side
+            .iter()
+            .filter(|s| s.alive)
+            .map(|s| s.player.attributes.aggression as u32 + 5)
+            .sum();
+        let mut roll = rng.gen_range(0..total);
+        for (index, sim) in side.iter().enumerate() {
+            if !sim.alive {
+                continue;
+            }
+            let weight = sim.player.attributes.aggression as u32 + 5;
+            if roll < weight {
+                return index;
+            }
+            roll -= weight;
+        }
+        unreachable!("pick_duelist called with no players alive")
+    }
+
+    fn duel_rating(&self, team: usize, sim: &SimPlayer, pressure: bool, clutch: bool) -> f32 {
+        let player = sim.player;
+        let a = &player.attributes;
+        let mut rating = a.aim as f32 * 0.4
+            + a.movement as f32 * 0.15
+            + a.game_sense as f32 * 0.2
+            + a.utility_usage as f32 * 0.1
+            + a.composure as f32 * 0.1
+            + a.aggression as f32 * 0.05;
+
+        if clutch {
+            rating = rating * 0.6 + a.clutch_potential as f32 * 0.4;
+        }
+        if pressure {
+            rating = rating * 0.8 + a.composure as f32 * 0.2;
+        }
+
+        rating
+            * Self::agent_modifier(sim)
+            * self.composition_modifier(team)
+            * Self::loadout_modifier(&sim.economy)
+            * Self::morale_modifier(player.morale)
+            * condition_modifier(player.fatigue)
+            * self.cohesion_modifier(team)
+            * self.map_modifier(team)
+    }
+
+    fn agent_modifier(sim: &SimPlayer) -> f32 {
+        match sim.agent {
+            Some(agent) => 0.9 + sim.player.get_agent_proficiency(agent) as f32 / 100.0,
+            None => 0.95,
+        }
+    }
+
+    /// Unbalanced compositions are punished, and agents that suit the map
+    /// give a small edge.
+    fn composition_modifier(&self, team: usize) -> f32 {
+        let agents: Vec<Agent> = self.agents[team].iter().map(|&(_, agent)| agent).collect();
+        if agents.is_empty() {
+            return 1.0;
+        }
+        let balance = if TeamComposition::from_agents(&agents).is_balanced() {
+            1.0
+        } else {
+            0.96
+        };
+        let affinity = agents.iter().map(|a| a.map_affinity(&self.map) as f32).sum::<f32>() / agents.len() as f32;
+        balance * (1.0 + (affinity - 12.0) / 200.0)
+    }
+
+    fn has_ability_alive(side: &[SimPlayer], ability: AbilityType) -> bool {
+        side.iter()
+            .any(|s| s.alive && s.agent.map(|a| a.has_ability(ability)).unwrap_or(false))
+    }
+
+    /// A pistol against a rifle is a big handicap; armour takes the edge off.
+    fn loadout_modifier(economy: &RoundEconomy) -> f32 {
+        0.8 + 0.2 * economy.weapons_cost.min(2900) as f32 / 2900.0
+            + 0.05 * economy.armor_cost.min(1000) as f32 / 1000.0
+    }
+
+    fn morale_modifier(morale: Morale) -> f32 {
+        0.9 + (morale as u8 - 1) as f32 * 0.05
+    }
+
+    fn cohesion_modifier(&self, team: usize) -> f32 {
+        0.95 + self.teams[team].team_cohesion as f32 / 200.0
+    }
+
+    fn map_modifier(&self, team: usize) -> f32 {
+        0.95 + self.teams[team].get_map_proficiency(&self.map) as f32 / 200.0
+    }
+
+    /// Coordinated utility from the whole lineup makes every duel a little
+    /// easier, as long as the team actually bought some this round.
+    fn utility_modifier(&self, side: &[SimPlayer], team: usize) -> f32 {
+        let lineup = &self.lineups[team];
+        if lineup.is_empty() {
+            return 1.0;
+        }
+        let total: u32 = lineup
+            .iter()
+            .map(|p| p.attributes.utility_usage as u32 + p.attributes.communication as u32)
+            .sum();
+        let average = total as f32 / (lineup.len() * 2) as f32;
+        let bought = side.iter().map(|s| s.economy.utility_cost.min(800)).sum::<u32>() as f32
+            / (side.len() as f32 * 800.0);
+        1.0 + (average - 13.0) / 100.0 * bought + 0.03 * bought
+    }
+
+    fn alive_count(side: &[SimPlayer]) -> usize {
+        side.iter().filter(|s| s.alive).count()
+    }
+
+    fn average_alive(side: &[SimPlayer], attribute: impl Fn(&Player) -> u8) -> f64 {
+        let alive: Vec<_> = side.iter().filter(|s| s.alive).collect();
+        if alive.is_empty() {
+            return 0.0;
+        }
+        alive.iter().map(|s| attribute(s.player) as f64).sum::<f64>() / alive.len() as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::rng::seeded_rng;
+
+    /// Two teams with `size1` and `size2` players, all of them starting.
+    fn teams(size1: usize, size2: usize, rng: &mut impl Rng) -> (Team, Team, Vec<Player>) {
+        let mut players = Vec::new();
+        let mut team1 = Team::new("Team 1".to_string(), rng);
+        let mut team2 = Team::new("Team 2".to_string(), rng);
+        for (team, size) in [(&mut team1, size1), (&mut team2, size2)] {
+            for i in 0..size {
+                let player = Player::new(format!("{} Player {}", team.name, i + 1), rng);
+                team.add_player(player.id);
+                players.push(player);
+            }
+        }
+        (team1, team2, players)
+    }
+
+    fn simulate(seed: u64, size1: usize, size2: usize) -> MatchResult {
+        let mut rng = seeded_rng(seed);
+        let (team1, team2, players) = teams(size1, size2, &mut rng);
+        MatchSimulator::new(&team1, &team2, &players, "Ascent".to_string(), MatchType::Regular).simulate(&mut rng)
+    }
+
+    fn team_total(result: &MatchResult, team_id: Uuid, stat: impl Fn(&PlayerMatchStats) -> u32) -> u32 {
+        let is_team1 = team_id == result.team1_id;
+        // Team 1's players come first
+        let (first, second) = result.player_stats.split_at(result.player_stats.len() / 2);
+        if is_team1 { first } else { second }.iter().map(stat).sum()
+    }
+
+    #[test]
+    fn maps_go_to_13_and_overtime_is_won_by_two() {
+        let mut overtime = false;
+        for seed in 0..60 {
+            let result = simulate(seed, 5, 5);
+            let (high, low) = (
+                result.team1_score.max(result.team2_score),
+                result.team1_score.min(result.team2_score),
+            );
+            if low < ROUNDS_TO_WIN - 1 {
+                assert_eq!(high, ROUNDS_TO_WIN);
+            } else {
+                assert_eq!(high - low, 2);
+                overtime = true;
+            }
+            assert_eq!(result.rounds.len(), (high + low) as usize);
+
+            let team1_rounds = result.rounds.iter().filter(|r| r.winner_id == result.team1_id).count();
+            assert_eq!(team1_rounds, result.team1_score as usize);
+            let winner = if result.team1_score > result.team2_score { result.team1_id } else { result.team2_id };
+            assert_eq!(result.winner_id, Some(winner));
+        }
+        assert!(overtime, "no map went to overtime");
+    }
+
+    #[test]
+    fn every_kill_has_a_death() {
+        for seed in 0..20 {
+            let result = simulate(seed, 5, 5);
+            let rounds = result.rounds.len() as u32;
+            let kills = |team| team_total(&result, team, |s| s.kills as u32);
+            let deaths = |team| team_total(&result, team, |s| s.deaths as u32);
+
+            assert_eq!(kills(result.team1_id), deaths(result.team2_id));
+            assert_eq!(kills(result.team2_id), deaths(result.team1_id));
+            // Nobody dies more than once a round, and the round ends when a side is wiped
+            for team in [result.team1_id, result.team2_id] {
+                assert!(deaths(team) <= rounds * 5);
+            }
+            assert!(deaths(result.team1_id) + deaths(result.team2_id) <= rounds * 9);
+            for stats in &result.player_stats {
+                assert!(stats.deaths as u32 <= rounds);
+            }
+        }
+    }
+
+    #[test]
+    fn stat_totals_add_up() {
+        for seed in 0..20 {
+            let result = simulate(seed, 5, 5);
+            assert_eq!(result.player_stats.len(), 10);
+
+            let first_kills: u32 = result.player_stats.iter().map(|s| s.first_kills as u32).sum();
+            assert_eq!(first_kills as usize, result.rounds.iter().filter(|r| r.first_kill_player_id.is_some()).count());
+            for team in [result.team1_id, result.team2_id] {
+                // At most two teammates share each kill
+                let kills = team_total(&result, team, |s| s.kills as u32);
+                assert!(team_total(&result, team, |s| s.assists as u32) <= kills * 2);
+            }
+            for stats in &result.player_stats {
+                assert!(stats.clutches_won <= stats.clutches_attempted);
+                assert!((0.0..=100.0).contains(&stats.headshot_percentage));
+                assert!(stats.damage >= stats.kills as u32 * 130);
+            }
+        }
+    }
+
+    #[test]
+    fn a_short_handed_side_still_plays_the_map_out() {
+        for (size1, size2) in [(3, 5), (0, 5)] {
+            let result = simulate(1, size1, size2);
+            assert_eq!(result.player_stats.len(), size1 + size2);
+            assert_eq!(result.winner_id, Some(result.team2_id));
+        }
+    }
+}
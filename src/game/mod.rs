@@ -1,18 +1,68 @@
 pub mod player;
+pub mod agent;
 pub mod team;
 pub mod match_simulation;
 pub mod economy;
 pub mod tournament;
+pub mod bracket;
+pub mod series;
+pub mod tactics;
+pub mod training;
+pub mod condition;
+pub mod injury;
+pub mod contract;
+pub mod transfer;
+pub mod scouting;
+pub mod aging;
+pub mod ai;
+pub mod news;
+pub mod save;
+pub mod stats;
+pub mod rng;
 
 use serde::{Deserialize, Serialize};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, Duration, TimeZone, Utc, Weekday};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashSet;
 use uuid::Uuid;
 
 pub use player::*;
+pub use agent::*;
 pub use team::*;
 pub use match_simulation::*;
 pub use economy::*;
 pub use tournament::*;
+pub use bracket::*;
+pub use series::*;
+pub use tactics::*;
+pub use training::*;
+pub use condition::*;
+pub use injury::*;
+pub use contract::*;
+pub use transfer::*;
+pub use scouting::*;
+pub use aging::*;
+pub use ai::*;
+pub use news::*;
+pub use save::*;
+pub use stats::*;
+pub use rng::*;
+
+/// Newly generated teenagers joining the free-agent pool each year.
+const YOUTH_INTAKE_SIZE: usize = 24;
+
+/// Cost of adding scouts to the network.
+pub const SCOUTING_UPGRADE_COST: i64 = 100_000;
+
+/// Players a team needs to field a side.
+pub const MIN_ROSTER_SIZE: usize = 5;
+
+/// Daily chance of an AI club bidding for each transfer-listed player.
+const DAILY_BID_CHANCE: f64 = 0.08;
+
+/// Days an incoming bid stays on the table.
+const BID_VALID_DAYS: i64 = 14;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameState {
@@ -23,7 +73,18 @@ pub struct GameState {
     pub tournament_state: TournamentState,
     pub all_players: Vec<Player>,
     pub all_teams: Vec<Team>,
+    pub scouting: ScoutingNetwork,
+    pub retired_players: Vec<RetiredPlayer>,
+    pub news: Vec<NewsItem>,
+    pub transfers: Vec<TransferRecord>,
+    pub incoming_bids: Vec<IncomingBid>,
+    pub loans: Vec<Loan>,
     pub match_history: Vec<MatchResult>,
+    pub series_history: Vec<Series>,
+    pub seed: u64,
+    pub rng: GameRng,
+    #[serde(skip)]
+    autosave_requests: Vec<AutosaveTrigger>,
 }
 
 impl Default for GameState {
@@ -34,25 +95,48 @@ impl Default for GameState {
 
 impl GameState {
     pub fn new() -> Self {
+        Self::with_seed(rand::random())
+    }
+
+    /// A fresh game whose world, scouting and matches all follow from `seed`.
+    pub fn with_seed(seed: u64) -> Self {
+        let current_season = 2025;
         Self {
             current_team: None,
-            current_season: 2025,
+            current_season,
             budget: 5_000_000, // Starting budget of $5M - increased for better player affordability
-            current_date: Utc::now(),
+            current_date: Self::season_start(current_season),
             tournament_state: TournamentState::new(),
             all_players: Vec::new(),
             all_teams: Vec::new(),
+            scouting: ScoutingNetwork::new(),
+            retired_players: Vec::new(),
+            news: Vec::new(),
+            transfers: Vec::new(),
+            incoming_bids: Vec::new(),
+            loans: Vec::new(),
             match_history: Vec::new(),
+            series_history: Vec::new(),
+            seed,
+            rng: seeded_rng(seed),
+            autosave_requests: Vec::new(),
         }
     }
 
+    /// Seasons start on the first of January.
+    pub fn season_start(season: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(season as i32, 1, 1, 0, 0, 0)
+            .single()
+            .unwrap_or_default()
+    }
+
     pub fn initialize_with_team(&mut self, team_name: String) {
         // Create a new team with basic roster
-        let mut team = Team::new(team_name);
+        let mut team = Team::new(team_name, &mut self.rng);
         
         // Generate initial roster
         for i in 0..5 {
-            let player = Player::generate_random(format!("Player{}", i + 1));
+            let player = Player::generate_random(format!("Player{}", i + 1), &mut self.rng);
             team.add_player(player.id);
             self.all_players.push(player);
         }
@@ -61,15 +145,18 @@ impl GameState {
         
         // Generate other teams for competition
         self.generate_league_teams();
+        self.assign_initial_contracts();
+        self.youth_intake();
+        self.update_schedule();
     }
 
     pub fn initialize_with_existing_team(&mut self, team_name: String, team_nickname: String, region: String) {
         // Create the player's chosen team
-        let mut team = Team::new_with_details(team_name, team_nickname, region);
+        let mut team = Team::new_with_details(team_name, team_nickname, region, &mut self.rng);
         
         // Generate initial roster for the player's team
         for i in 0..5 {
-            let player = Player::generate_random(format!("{}_Player{}", team.nickname, i + 1));
+            let player = Player::generate_random(format!("{}_Player{}", team.nickname, i + 1), &mut self.rng);
             team.add_player(player.id);
             self.all_players.push(player);
         }
@@ -78,6 +165,18 @@ impl GameState {
         
         // Generate all other teams from all leagues
         self.generate_all_league_teams();
+        self.assign_initial_contracts();
+        self.youth_intake();
+        self.update_schedule();
+    }
+
+    /// Builds the full four-league world with every team AI-controlled.
+    pub fn initialize_world(&mut self) {
+        self.current_team = None;
+        self.generate_all_league_teams();
+        self.assign_initial_contracts();
+        self.youth_intake();
+        self.update_schedule();
     }
 
     fn generate_league_teams(&mut self) {
@@ -87,11 +186,11 @@ impl GameState {
         ];
 
         for name in team_names {
-            let mut team = Team::new(name.to_string());
+            let mut team = Team::new(name.to_string(), &mut self.rng);
             
             // Generate roster for each team
             for i in 0..5 {
-                let player = Player::generate_random(format!("{}_Player{}", name, i + 1));
+                let player = Player::generate_random(format!("{}_Player{}", name, i + 1), &mut self.rng);
                 team.add_player(player.id);
                 self.all_players.push(player);
             }
@@ -138,7 +237,7 @@ impl GameState {
 
         for (name, nickname) in americas_teams {
             if current_team_name.as_ref() != Some(&name.to_string()) {
-                let mut team = Team::new_with_details(name.to_string(), nickname.to_string(), "Americas".to_string());
+                let mut team = Team::new_with_details(name.to_string(), nickname.to_string(), "Americas".to_string(), &mut self.rng);
                 self.generate_team_roster(&mut team);
                 self.all_teams.push(team);
             }
@@ -146,7 +245,7 @@ impl GameState {
 
         for (name, nickname) in emea_teams {
             if current_team_name.as_ref() != Some(&name.to_string()) {
-                let mut team = Team::new_with_details(name.to_string(), nickname.to_string(), "EMEA".to_string());
+                let mut team = Team::new_with_details(name.to_string(), nickname.to_string(), "EMEA".to_string(), &mut self.rng);
                 self.generate_team_roster(&mut team);
                 self.all_teams.push(team);
             }
@@ -154,7 +253,7 @@ impl GameState {
 
         for (name, nickname) in pacific_teams {
             if current_team_name.as_ref() != Some(&name.to_string()) {
-                let mut team = Team::new_with_details(name.to_string(), nickname.to_string(), "Pacific".to_string());
+                let mut team = Team::new_with_details(name.to_string(), nickname.to_string(), "Pacific".to_string(), &mut self.rng);
                 self.generate_team_roster(&mut team);
                 self.all_teams.push(team);
             }
@@ -162,7 +261,7 @@ impl GameState {
 
         for (name, nickname) in china_teams {
             if current_team_name.as_ref() != Some(&name.to_string()) {
-                let mut team = Team::new_with_details(name.to_string(), nickname.to_string(), "China".to_string());
+                let mut team = Team::new_with_details(name.to_string(), nickname.to_string(), "China".to_string(), &mut self.rng);
                 self.generate_team_roster(&mut team);
                 self.all_teams.push(team);
             }
@@ -171,12 +270,508 @@ impl GameState {
 
     fn generate_team_roster(&mut self, team: &mut Team) {
         for i in 0..5 {
-            let player = Player::generate_random(format!("{}_Player{}", team.nickname, i + 1));
+            let player = Player::generate_random(format!("{}_Player{}", team.nickname, i + 1), &mut self.rng);
             team.add_player(player.id);
             self.all_players.push(player);
         }
     }
 
+    /// Signs every rostered player in a new world to a contract running one
+    /// to four seasons.
+    fn assign_initial_contracts(&mut self) {
+        let signed: HashSet<Uuid> = self
+            .current_team
+            .iter()
+            .chain(self.all_teams.iter())
+            .flat_map(|t| t.players.iter().copied())
+            .collect();
+        for player in self.all_players.iter_mut().filter(|p| signed.contains(&p.id)) {
+            let years = self.rng.gen_range(1..=4);
+            player.contract_expires = Some(Self::season_start(self.current_season + years));
+        }
+    }
+
+    /// Adds this year's crop of teenagers to the free-agent pool.
+    fn youth_intake(&mut self) {
+        for _ in 0..YOUTH_INTAKE_SIZE {
+            let player = Player::new_youth(Player::random_handle(&mut self.rng), &mut self.rng);
+            self.all_players.push(player);
+        }
+    }
+
+    /// Players not signed to any team, open to offers from the user and AI
+    /// clubs alike.
+    pub fn free_agents(&self) -> impl Iterator<Item = &Player> {
+        let signed: HashSet<Uuid> = self
+            .current_team
+            .iter()
+            .chain(self.all_teams.iter())
+            .flat_map(|t| t.players.iter().copied())
+            .collect();
+        self.all_players.iter().filter(move |p| !signed.contains(&p.id))
+    }
+
+    /// Sends the scouts out to watch `count` free agents at random and
+    /// returns who they saw. The pool is topped up with journeymen if it
+    /// runs short.
+    pub fn scout_free_agents(&mut self, count: usize) -> Vec<Uuid> {
+        let mut pool: Vec<Uuid> = self.free_agents().map(|p| p.id).collect();
+        while pool.len() < count {
+            let player = Player::new(Player::random_handle(&mut self.rng), &mut self.rng);
+            pool.push(player.id);
+            self.all_players.push(player);
+        }
+
+        let scouted: Vec<Uuid> = pool.choose_multiple(&mut self.rng, count).copied().collect();
+        for &player_id in &scouted {
+            self.scouting.scout(player_id, &mut self.rng);
+        }
+        scouted
+    }
+
+    /// Adds scouts to the network, making every future report more accurate.
+    pub fn upgrade_scouting(&mut self) -> bool {
+        if self.scouting.accuracy >= 100 || self.budget < SCOUTING_UPGRADE_COST {
+            return false;
+        }
+        self.budget -= SCOUTING_UPGRADE_COST;
+        self.scouting.accuracy = (self.scouting.accuracy + 10).min(100);
+        true
+    }
+
+    /// Opens talks with a free agent about joining the user's team. There's
+    /// no club to pay, so only personal terms are agreed.
+    pub fn open_free_agent_negotiation(&mut self, player_id: Uuid) -> Option<Negotiation> {
+        if !self.free_agents().any(|p| p.id == player_id) {
+            return None;
+        }
+        let team = self.current_team.as_ref()?;
+        let player = self.all_players.iter().find(|p| p.id == player_id)?;
+        let win_rate = if team.wins + team.losses == 0 { 0.5 } else { team.get_win_rate() };
+        let mut negotiation = Negotiation::open(player, false, win_rate, &mut self.rng);
+        negotiation.free_agent = true;
+        Some(negotiation)
+    }
+
+    /// Puts one of the user's players on, or takes them off, the transfer
+    /// list. Being listed unsettles the player; AI clubs start bidding for
+    /// them from the next day.
+    pub fn set_transfer_listed(&mut self, player_id: Uuid, listed: bool) -> bool {
+        let Some(team) = &self.current_team else {
+            return false;
+        };
+        if !team.players.contains(&player_id) {
+            return false;
+        }
+        let Some(player) = self.all_players.iter_mut().find(|p| p.id == player_id) else {
+            return false;
+        };
+        if listed && !player.transfer_listed {
+            player.decrease_morale();
+        }
+        player.transfer_listed = listed;
+        if !listed {
+            self.incoming_bids.retain(|bid| bid.player_id != player_id);
+        }
+        true
+    }
+
+    /// Bids for the user's listed players from AI clubs that can afford
+    /// them. Stale bids, and bids for players no longer listed, lapse.
+    fn receive_transfer_bids(&mut self) {
+        let date = self.current_date;
+        let Some(team) = &self.current_team else {
+            return;
+        };
+        let listed: Vec<(Uuid, String, i64)> = self
+            .all_players
+            .iter()
+            .filter(|p| p.transfer_listed && team.players.contains(&p.id))
+            .map(|p| (p.id, p.name.clone(), p.market_value))
+            .collect();
+        self.incoming_bids
+            .retain(|bid| bid.expires > date && listed.iter().any(|(id, _, _)| *id == bid.player_id));
+
+        for (player_id, name, value) in listed {
+            if !self.rng.gen_bool(DAILY_BID_CHANCE) {
+                continue;
+            }
+            let fee = round_to_thousand(value as f64 * self.rng.gen_range(0.7..1.2));
+            let bidders: Vec<&Team> = self
+                .all_teams
+                .iter()
+                .filter(|t| t.budget >= fee)
+                .filter(|t| !self.incoming_bids.iter().any(|b| b.player_id == player_id && b.team_id == t.id))
+                .collect();
+            let Some(club) = bidders.choose(&mut self.rng) else {
+                continue;
+            };
+            let headline = format!("{} bid ${} for {}", club.name, fee, name);
+            let bid = IncomingBid {
+                id: random_uuid(&mut self.rng),
+                player_id,
+                team_id: club.id,
+                fee,
+                expires: date + Duration::days(BID_VALID_DAYS),
+            };
+            self.incoming_bids.push(bid);
+            self.post_news(headline);
+        }
+    }
+
+    pub fn bids_for(&self, player_id: Uuid) -> impl Iterator<Item = &IncomingBid> {
+        self.incoming_bids.iter().filter(move |bid| bid.player_id == player_id)
+    }
+
+    pub fn reject_bid(&mut self, bid_id: Uuid) {
+        self.incoming_bids.retain(|bid| bid.id != bid_id);
+    }
+
+    /// Sells a player to the AI club behind a bid. The club pays the fee
+    /// and signs them to a new deal; selling a popular player upsets the
+    /// rest of the squad.
+    pub fn accept_bid(&mut self, bid_id: Uuid) -> bool {
+        let Some(bid) = self.incoming_bids.iter().find(|b| b.id == bid_id).cloned() else {
+            return false;
+        };
+        if !self.all_teams.iter().any(|t| t.id == bid.team_id && t.budget >= bid.fee) {
+            return false;
+        }
+        let Some((from_team, popular)) = self.leave_user_team(bid.player_id) else {
+            return false;
+        };
+        let years = self.rng.gen_range(1..=3);
+        let Some(club) = self.all_teams.iter_mut().find(|t| t.id == bid.team_id) else {
+            return false;
+        };
+        club.budget -= bid.fee;
+        club.add_player(bid.player_id);
+        let club_name = club.name.clone();
+        self.budget += bid.fee;
+
+        let Some(player) = self.all_players.iter_mut().find(|p| p.id == bid.player_id) else {
+            return false;
+        };
+        player.contract_expires = Some(Self::season_start(self.current_season + years));
+        let name = player.name.clone();
+        self.record_transfer(bid.player_id, Some(from_team), Some(bid.team_id), bid.fee, TransferKind::Transfer);
+        self.post_news(format!("{} sign {} for ${}", club_name, name, bid.fee));
+        if popular {
+            self.unsettle_squad(&name);
+        }
+        true
+    }
+
+    /// Sends a player out on loan for the rest of the season, to an AI club
+    /// where they'd get a game if there is one. Returns the club.
+    pub fn loan_out(&mut self, player_id: Uuid) -> Option<Uuid> {
+        let ability = self.get_player_by_id(player_id)?.current_ability;
+        let players = &self.all_players;
+        let would_start: Vec<Uuid> = self
+            .all_teams
+            .iter()
+            .filter(|t| {
+                t.starting_lineup
+                    .iter()
+                    .filter_map(|id| players.iter().find(|p| p.id == *id))
+                    .any(|p| p.current_ability < ability)
+            })
+            .map(|t| t.id)
+            .collect();
+        let candidates: Vec<Uuid> = if would_start.is_empty() {
+            self.all_teams.iter().map(|t| t.id).collect()
+        } else {
+            would_start
+        };
+        let club_id = *candidates.choose(&mut self.rng)?;
+
+        let (from_team, _) = self.leave_user_team(player_id)?;
+        let club = self.all_teams.iter_mut().find(|t| t.id == club_id)?;
+        club.add_player(player_id);
+        let club_name = club.name.clone();
+        self.loans.push(Loan {
+            player_id,
+            from_team,
+            to_team: club_id,
+            return_date: Self::season_start(self.current_season + 1),
+        });
+        let name = self.get_player_by_id(player_id).map(|p| p.name.clone()).unwrap_or_default();
+        self.record_transfer(player_id, Some(from_team), Some(club_id), 0, TransferKind::Loan);
+        self.post_news(format!("{} joins {} on loan", name, club_name));
+        Some(club_id)
+    }
+
+    /// Brings players whose loans have run their course back to the user's
+    /// team.
+    fn return_loans(&mut self) {
+        let date = self.current_date;
+        let (returning, ongoing): (Vec<Loan>, Vec<Loan>) =
+            std::mem::take(&mut self.loans).into_iter().partition(|loan| loan.return_date <= date);
+        self.loans = ongoing;
+
+        for loan in returning {
+            let Some(club) = self.all_teams.iter_mut().find(|t| t.id == loan.to_team) else {
+                continue;
+            };
+            club.remove_player(loan.player_id);
+            if let Some(team) = self.current_team.as_mut().filter(|t| t.id == loan.from_team) {
+                team.add_player(loan.player_id);
+            }
+        }
+    }
+
+    /// Releases one of the user's players, paying off the rest of their
+    /// contract. Returns the payout, or None if the player isn't on the
+    /// user's team, the squad is already down to `MIN_ROSTER_SIZE` or the
+    /// budget can't cover the payout.
+    pub fn release_player(&mut self, player_id: Uuid) -> Option<i64> {
+        let payout = termination_payout(self.get_player_by_id(player_id)?, self.current_date);
+        if payout > self.budget {
+            return None;
+        }
+        let (from_team, popular) = self.leave_user_team(player_id)?;
+        self.budget -= payout;
+
+        let player = self.all_players.iter_mut().find(|p| p.id == player_id)?;
+        player.contract_expires = None;
+        let name = player.name.clone();
+        self.record_transfer(player_id, Some(from_team), None, payout, TransferKind::Release);
+        let team_name = self.current_team.as_ref().map(|t| t.name.clone()).unwrap_or_default();
+        self.post_news(format!("{} release {}", team_name, name));
+        if popular {
+            self.unsettle_squad(&name);
+        }
+        Some(payout)
+    }
+
+    /// Takes a player off the user's roster, clearing their listing, bids
+    /// and any starting-place promise. Refused if it would leave the team
+    /// unable to field a side. Returns the team and whether the player was
+    /// popular in the dressing room.
+    fn leave_user_team(&mut self, player_id: Uuid) -> Option<(Uuid, bool)> {
+        let team = self.current_team.as_mut().filter(|t| t.players.contains(&player_id))?;
+        if team.players.len() <= MIN_ROSTER_SIZE {
+            return None;
+        }
+        let player = self.all_players.iter().find(|p| p.id == player_id)?;
+        let popular = is_popular(player, team, &self.all_players);
+        team.remove_player(player_id);
+        let team_id = team.id;
+
+        let player = self.all_players.iter_mut().find(|p| p.id == player_id)?;
+        player.transfer_listed = false;
+        player.starter_guarantee = false;
+        self.incoming_bids.retain(|bid| bid.player_id != player_id);
+        Some((team_id, popular))
+    }
+
+    /// The rest of the squad takes a popular teammate's exit badly.
+    fn unsettle_squad(&mut self, departed: &str) {
+        let Some(team) = &self.current_team else {
+            return;
+        };
+        for player in self.all_players.iter_mut().filter(|p| team.players.contains(&p.id)) {
+            player.decrease_morale();
+        }
+        let headline = format!("{}'s dressing room unsettled by the exit of {}", team.name, departed);
+        self.post_news(headline);
+    }
+
+    /// Asks an AI club whether it would sell one of its players for `fee`.
+    /// Fails for a bid that can't be made, such as one the budget can't
+    /// cover.
+    pub fn bid_for_player(&self, player_id: Uuid, fee: i64) -> Result<TransferResponse, BidError> {
+        if self.current_team.is_none() {
+            return Err(BidError::NoTeam);
+        }
+        if fee <= 0 {
+            return Err(BidError::InvalidFee);
+        }
+        if fee > self.budget {
+            return Err(BidError::CantAfford);
+        }
+        if self.loans.iter().any(|loan| loan.player_id == player_id) {
+            return Err(BidError::OnLoan);
+        }
+        let team = self.all_teams.iter().find(|t| t.players.contains(&player_id));
+        let (Some(team), Some(player)) = (team, self.get_player_by_id(player_id)) else {
+            return Err(BidError::NotAtAClub);
+        };
+        let price = asking_price(player, team, &self.all_players, self.current_date);
+        Ok(evaluate_bid(price, fee))
+    }
+
+    /// Opens personal terms with a player whose club has accepted `fee`.
+    /// The fee is only paid if the player agrees to join.
+    pub fn open_transfer_negotiation(&mut self, player_id: Uuid, fee: i64) -> Option<Negotiation> {
+        if self.bid_for_player(player_id, fee).ok()? != TransferResponse::Accepted {
+            return None;
+        }
+        let user_team = self.current_team.as_ref()?;
+        let club = self.all_teams.iter().find(|t| t.players.contains(&player_id))?;
+        let player = self.all_players.iter().find(|p| p.id == player_id)?;
+        let is_starter = club.starting_lineup.contains(&player_id);
+        let win_rate = if user_team.wins + user_team.losses == 0 { 0.5 } else { user_team.get_win_rate() };
+        let mut negotiation = Negotiation::open(player, is_starter, win_rate, &mut self.rng);
+        negotiation.transfer = Some(TransferBid { player_id, from_team: club.id, fee });
+        Some(negotiation)
+    }
+
+    /// Opens renewal talks with one of the user's players. Talks only open
+    /// once the contract is into its final season.
+    pub fn open_negotiation(&mut self, player_id: Uuid) -> Option<Negotiation> {
+        let team = self.current_team.as_ref().filter(|t| t.players.contains(&player_id))?;
+        let player = self
+            .all_players
+            .iter()
+            .find(|p| p.id == player_id)
+            .filter(|p| p.in_final_year(self.current_date))?;
+        let is_starter = team.starting_lineup.contains(&player_id);
+        let win_rate = if team.wins + team.losses == 0 { 0.5 } else { team.get_win_rate() };
+        Some(Negotiation::open(player, is_starter, win_rate, &mut self.rng))
+    }
+
+    /// Puts an offer to the player's agent. Accepted terms replace the
+    /// player's contract, with a renewal's length added on after the current
+    /// deal ends, and the signing bonus comes out of the budget, along
+    /// with the fee if the player is joining on a transfer. Walking out of
+    /// renewal talks leaves the player unhappy. Returns None for an offer
+    /// that can't be made, such as a bonus the budget can't cover or a free
+    /// agent who has signed elsewhere in the meantime.
+    pub fn submit_offer(&mut self, negotiation: &mut Negotiation, offer: ContractOffer) -> Option<NegotiationResponse> {
+        let fee = negotiation.transfer.as_ref().map_or(0, |bid| bid.fee);
+        if offer.salary <= 0 || offer.length == 0 || offer.signing_bonus < 0 || offer.signing_bonus + fee > self.budget {
+            return None;
+        }
+        self.get_player_by_id(negotiation.player_id)?;
+        if let Some(bid) = &negotiation.transfer {
+            // The player may have moved on since the fee was agreed
+            let club = self.all_teams.iter().find(|t| t.id == bid.from_team)?;
+            if self.current_team.is_none() || !club.players.contains(&bid.player_id) {
+                return None;
+            }
+        }
+        if negotiation.free_agent
+            && (self.current_team.is_none() || !self.free_agents().any(|p| p.id == negotiation.player_id))
+        {
+            return None;
+        }
+
+        let response = negotiation.respond(&offer);
+        if response == NegotiationResponse::Accepted {
+            if let Some(bid) = &negotiation.transfer {
+                self.complete_transfer(bid);
+            }
+            if negotiation.free_agent {
+                self.complete_free_signing(negotiation.player_id);
+            }
+        }
+        let player = self.all_players.iter_mut().find(|p| p.id == negotiation.player_id)?;
+        match response {
+            NegotiationResponse::Accepted => {
+                self.budget -= offer.signing_bonus;
+                player.contract_salary = offer.salary;
+                // A renewal extends the current deal; a transfer starts a new one
+                let first_season = match (negotiation.is_renewal(), player.contract_expires) {
+                    (true, Some(expires)) => expires.year().max(self.current_season as i32) as u32,
+                    _ => self.current_season,
+                };
+                player.contract_expires = Some(Self::season_start(first_season + offer.length as u32));
+                player.starter_guarantee = offer.starter_guarantee;
+                player.improve_morale();
+            }
+            NegotiationResponse::WalkedAway if negotiation.is_renewal() => player.decrease_morale(),
+            _ => {}
+        }
+        Some(response)
+    }
+
+    /// Moves a player to the user's team, paying the agreed fee to the
+    /// selling club, which then fills the gap from the free-agent pool.
+    fn complete_transfer(&mut self, bid: &TransferBid) {
+        let Some(team) = &mut self.current_team else {
+            return;
+        };
+        let Some(club) = self.all_teams.iter_mut().find(|t| t.id == bid.from_team) else {
+            return;
+        };
+        club.remove_player(bid.player_id);
+        club.budget += bid.fee;
+        team.add_player(bid.player_id);
+        self.budget -= bid.fee;
+
+        let name = self
+            .all_players
+            .iter()
+            .find(|p| p.id == bid.player_id)
+            .map(|p| p.name.clone())
+            .unwrap_or_default();
+        let headline = format!("{} sign {} from {} for ${}", team.name, name, club.name, bid.fee);
+        let team_id = team.id;
+        self.record_transfer(bid.player_id, Some(bid.from_team), Some(team_id), bid.fee, TransferKind::Transfer);
+        self.post_news(headline);
+        self.fill_ai_rosters();
+    }
+
+    /// Adds a free agent to the user's team.
+    fn complete_free_signing(&mut self, player_id: Uuid) {
+        let Some(team) = &mut self.current_team else {
+            return;
+        };
+        team.add_player(player_id);
+        let name = self.all_players.iter().find(|p| p.id == player_id).map(|p| p.name.clone()).unwrap_or_default();
+        let headline = format!("{} sign free agent {}", team.name, name);
+        let team_id = team.id;
+        self.record_transfer(player_id, None, Some(team_id), 0, TransferKind::FreeSigning);
+        self.post_news(headline);
+    }
+
+    fn record_transfer(&mut self, player_id: Uuid, from_team: Option<Uuid>, to_team: Option<Uuid>, fee: i64, kind: TransferKind) {
+        let player_name = self.get_player_by_id(player_id).map(|p| p.name.clone()).unwrap_or_default();
+        self.transfers.push(TransferRecord {
+            date: self.current_date,
+            player_id,
+            player_name,
+            from_team,
+            to_team,
+            fee,
+            kind,
+        });
+    }
+
+    /// Players promised a starting place grow unhappy on the bench.
+    fn check_starter_guarantees(&mut self) {
+        let Some(team) = &self.current_team else {
+            return;
+        };
+        for player in self.all_players.iter_mut().filter(|p| team.players.contains(&p.id)) {
+            if player.starter_guarantee
+                && player.is_available()
+                && !team.starting_lineup.contains(&player.id)
+                && self.rng.gen_bool(0.05)
+            {
+                player.decrease_morale();
+            }
+        }
+    }
+
+    /// Championship standings with their teams, best first.
+    pub fn standings(&self) -> Vec<(&Team, &ChampionshipStanding)> {
+        self.tournament_state
+            .championship_standings
+            .iter()
+            .filter_map(|standing| self.get_team(standing.team_id).map(|team| (team, standing)))
+            .collect()
+    }
+
+    /// The CP race within one league.
+    pub fn region_standings(&self, region: &str) -> Vec<(&Team, &ChampionshipStanding)> {
+        self.standings()
+            .into_iter()
+            .filter(|(team, _)| team.region == region)
+            .collect()
+    }
+
     pub fn get_player_by_id(&self, id: Uuid) -> Option<&Player> {
         self.all_players.iter().find(|p| p.id == id)
     }
@@ -185,12 +780,960 @@ impl GameState {
         self.all_players.iter_mut().find(|p| p.id == id)
     }
 
+    pub fn get_team(&self, id: Uuid) -> Option<&Team> {
+        Self::find_team(&self.current_team, &self.all_teams, id)
+    }
+
+    // Lets callers borrow a team while also holding other fields mutably
+    fn find_team<'a>(current_team: &'a Option<Team>, all_teams: &'a [Team], id: Uuid) -> Option<&'a Team> {
+        current_team.iter().chain(all_teams.iter()).find(|t| t.id == id)
+    }
+
+    pub fn get_team_mut(&mut self, id: Uuid) -> Option<&mut Team> {
+        self.current_team
+            .iter_mut()
+            .chain(self.all_teams.iter_mut())
+            .find(|t| t.id == id)
+    }
+
+    /// Plays out a series map by map and applies the result to the teams, the
+    /// players' match experience and fatigue, and the match history. Any veto steps that are
+    /// still open are taken by the AI for both teams.
+    pub fn play_series(&mut self, mut series: Series) -> Option<Series> {
+        let team1 = Self::find_team(&self.current_team, &self.all_teams, series.team1_id)?;
+        let team2 = Self::find_team(&self.current_team, &self.all_teams, series.team2_id)?;
+        if !series.is_veto_complete() {
+            series.run_ai_veto(team1, team2);
+        }
+
+        while let Some(series_map) = series.next_map() {
+            let mut result = MatchSimulator::new(
+                team1,
+                team2,
+                &self.all_players,
+                series_map.map.clone(),
+                series.match_type.clone(),
+            )
+            .with_team1_starting_side(series_map.team1_starting_side)
+            .simulate(&mut self.rng);
+            result.date = self.current_date;
+            series.record_map_result(result);
+        }
+
+        for team_id in [series.team1_id, series.team2_id] {
+            let won = series.winner_id == Some(team_id);
+            if let Some(team) = self.get_team_mut(team_id) {
+                team.record_match_result(won);
+            }
+        }
+
+        for stats in series.map_results().flat_map(|r| &r.player_stats) {
+            if let Some(player) = self.all_players.iter_mut().find(|p| p.id == stats.player_id) {
+                player.maps_this_week = player.maps_this_week.saturating_add(1);
+                add_fatigue(player, match_fatigue(player));
+            }
+        }
+
+        self.match_history.extend(series.map_results().cloned());
+        self.series_history.push(series.clone());
+        self.request_autosave(AutosaveTrigger::MatchPlayed);
+        Some(series)
+    }
+
+    /// A new series for a scheduled fixture, ready for the map veto.
+    pub fn fixture_series(&mut self, fixture_id: Uuid) -> Option<Series> {
+        let fixture = self.tournament_state.get_fixture(fixture_id)?;
+        if fixture.completed {
+            return None;
+        }
+        let (team1_id, team2_id, format, match_type) =
+            (fixture.team1_id, fixture.team2_id, fixture.format, fixture.match_type.clone());
+        Some(Series::new(team1_id, team2_id, format, match_type, &mut self.rng))
+    }
+
+    /// Plays a scheduled fixture and feeds the result into its event.
+    pub fn play_fixture(&mut self, fixture_id: Uuid, series: Series) -> Option<Series> {
+        let fixture = self.tournament_state.get_fixture(fixture_id)?;
+        if fixture.completed || fixture.team1_id != series.team1_id || fixture.team2_id != series.team2_id {
+            return None;
+        }
+
+        let series = self.play_series(series)?;
+        self.tournament_state.record_fixture_result(fixture_id, series.clone());
+        self.update_schedule();
+        Some(series)
+    }
+
+    /// The user's next unplayed fixture.
+    pub fn next_user_fixture(&self) -> Option<&Match> {
+        let team_id = self.current_team.as_ref()?.id;
+        self.tournament_state
+            .team_fixtures(team_id)
+            .find(|f| !f.completed)
+    }
+
+    /// The user's fixture if it's due today or overdue. Time doesn't move on
+    /// until it has been played.
+    pub fn user_fixture_due(&self) -> Option<&Match> {
+        self.next_user_fixture()
+            .filter(|f| f.scheduled_date <= self.current_date)
+    }
+
+    /// Every league and its teams, the user's team included.
+    pub fn teams_by_region(&self) -> Vec<(String, Vec<Uuid>)> {
+        let mut regions: Vec<(String, Vec<Uuid>)> = Vec::new();
+        for team in self.current_team.iter().chain(self.all_teams.iter()) {
+            match regions.iter_mut().find(|(region, _)| *region == team.region) {
+                Some((_, teams)) => teams.push(team.id),
+                None => regions.push((team.region.clone(), vec![team.id])),
+            }
+        }
+        regions
+    }
+
+    /// Moves the tournament on, tires out teams flying to an international
+    /// event and pays out prize money for any event that finished on the
+    /// way.
+    fn update_schedule(&mut self) {
+        let teams_by_region = self.teams_by_region();
+        let events_before = self.tournament_state.history.len();
+        let event_start = self.tournament_state.event_start;
+        self.tournament_state.update_schedule(
+            self.current_date,
+            Self::season_start(self.current_season),
+            &teams_by_region,
+            &mut self.rng,
+        );
+        if self.tournament_state.event_start != event_start && self.tournament_state.current_event.is_international() {
+            self.travel_to_event();
+        }
+
+        let user_team_id = self.current_team.as_ref().map(|t| t.id);
+        let placements: Vec<(Uuid, i64)> = self.tournament_state.history[events_before..]
+            .iter()
+            .flat_map(|result| &result.placements)
+            .map(|p| (p.team_id, p.prize_money))
+            .collect();
+        for (team_id, prize_money) in placements {
+            if Some(team_id) == user_team_id {
+                self.budget += prize_money;
+            } else if let Some(team) = self.all_teams.iter_mut().find(|t| t.id == team_id) {
+                team.budget += prize_money;
+            }
+        }
+    }
+
+    /// Every player on a team qualified for the event picks up travel
+    /// fatigue.
+    fn travel_to_event(&mut self) {
+        let travelling: HashSet<Uuid> = self
+            .tournament_state
+            .qualified_teams
+            .iter()
+            .filter_map(|&id| self.get_team(id))
+            .flat_map(|t| t.players.iter().copied())
+            .collect();
+        for player in self.all_players.iter_mut().filter(|p| travelling.contains(&p.id)) {
+            add_fatigue(player, TRAVEL_FATIGUE);
+        }
+    }
+
+    fn report_burnout(&mut self, player_id: Uuid) {
+        let Some(player) = self.get_player_by_id(player_id) else {
+            return;
+        };
+        let team = self
+            .current_team
+            .iter()
+            .chain(self.all_teams.iter())
+            .find(|t| t.players.contains(&player_id));
+        let headline = match team {
+            Some(team) => format!("{}'s {} steps away for {} days with burnout", team.name, player.name, player.break_days),
+            None => format!("{} steps away for {} days with burnout", player.name, player.break_days),
+        };
+        self.post_news(headline);
+    }
+
+    fn report_injury(&mut self, player_id: Uuid, event: InjuryEvent) {
+        let Some(player) = self.get_player_by_id(player_id) else {
+            return;
+        };
+        let Some(team) = self
+            .current_team
+            .iter()
+            .chain(self.all_teams.iter())
+            .find(|t| t.players.contains(&player_id))
+        else {
+            return; // Nobody follows free agents' injuries
+        };
+        let Some(injury) = player.current_injury() else {
+            return;
+        };
+        let return_date = injury.return_date.format("%Y-%m-%d");
+        let headline = match event {
+            InjuryEvent::Injured => format!(
+                "{}'s {} ruled out until {} ({}, {})",
+                team.name, player.name, return_date, injury.kind.name(), injury.severity.name().to_lowercase()
+            ),
+            InjuryEvent::Relapsed => format!(
+                "Setback for {}'s {}: {} flares up, out until {}",
+                team.name, player.name, injury.kind.name().to_lowercase(), return_date
+            ),
+            InjuryEvent::Recovered => return,
+        };
+        self.post_news(headline);
+    }
+
+    /// Takes unavailable players out of every starting lineup, bringing in
+    /// the best fit player from the bench. AI teams pick a fresh
+    /// role-balanced five each day, so their best players come back in once
+    /// they're fit again.
+    fn update_lineups(&mut self) {
+        let players = &self.all_players;
+        let ability = |id: &Uuid| {
+            players
+                .iter()
+                .find(|p| p.id == *id && p.is_available())
+                .map(|p| p.current_ability)
+        };
+
+        if let Some(team) = &mut self.current_team {
+            team.starting_lineup.retain(|id| ability(id).is_some());
+            while team.starting_lineup.len() < 5 {
+                let substitute = team
+                    .players
+                    .iter()
+                    .filter(|id| !team.starting_lineup.contains(id))
+                    .filter_map(|id| ability(id).map(|a| (*id, a)))
+                    .max_by_key(|&(_, a)| a);
+                let Some((player_id, _)) = substitute else {
+                    break;
+                };
+                team.starting_lineup.push(player_id);
+            }
+        }
+
+        for team in &mut self.all_teams {
+            team.starting_lineup = pick_lineup(team, players);
+        }
+    }
+
+    /// Simulates every AI-vs-AI fixture that has fallen due, including any
+    /// follow-up matches the results make due on the same day.
+    fn play_due_fixtures(&mut self) {
+        let user_team_id = self.current_team.as_ref().map(|t| t.id);
+        loop {
+            self.update_schedule();
+            let due: Vec<Uuid> = self
+                .tournament_state
+                .due_fixtures(self.current_date)
+                .filter(|f| user_team_id.is_none_or(|id| !f.involves(id)))
+                .map(|f| f.id)
+                .collect();
+            if due.is_empty() {
+                break;
+            }
+            for fixture_id in due {
+                if let Some(series) = self.fixture_series(fixture_id) {
+                    self.play_fixture(fixture_id, series);
+                }
+            }
+        }
+    }
+
+    /// Moves on up to a week, stopping early on the day the user has a match
+    /// or when the off-season is over.
+    pub fn advance_week(&mut self) {
+        for _ in 0..7 {
+            if self.user_fixture_due().is_some() || self.is_rollover_due() {
+                break;
+            }
+            self.advance_day();
+        }
+        self.request_autosave(AutosaveTrigger::WeekAdvanced);
+    }
+
+    /// Asks the app to autosave once the current action has finished.
+    pub fn request_autosave(&mut self, trigger: AutosaveTrigger) {
+        self.autosave_requests.push(trigger);
+    }
+
+    pub fn take_autosave_requests(&mut self) -> Vec<AutosaveTrigger> {
+        std::mem::take(&mut self.autosave_requests)
+    }
+
     pub fn advance_day(&mut self) {
-        self.current_date = self.current_date + chrono::Duration::days(1);
+        if self.is_rollover_due() {
+            return;
+        }
+        self.current_date += chrono::Duration::days(1);
+        if self.current_date.day() == 1 {
+            self.budget -= self.monthly_wage_bill();
+        }
         
-        // Update player morale, training effects, etc.
+        // Update player morale, rest, training effects, etc.
+        let training_day = self.current_date.weekday() == Weekday::Mon;
+        let mut burnt_out = Vec::new();
+        let mut injuries = Vec::new();
+        for player in &mut self.all_players {
+            player.daily_update(&mut self.rng);
+            rest(player);
+            if training_day && player.is_available() {
+                train_player(player, &mut self.rng);
+            }
+            if check_burnout(player, &mut self.rng) {
+                burnt_out.push(player.id);
+            }
+            if let Some(event) = check_injury(player, self.current_date, &mut self.rng) {
+                injuries.push((player.id, event));
+            }
+        }
+        for player_id in burnt_out {
+            self.report_burnout(player_id);
+        }
+        for (player_id, event) in injuries {
+            self.report_injury(player_id, event);
+        }
+        self.update_lineups();
+        self.check_starter_guarantees();
+        self.receive_transfer_bids();
+
+        self.play_due_fixtures();
+
+        if self.is_rollover_due() {
+            self.request_autosave(AutosaveTrigger::SeasonRollover);
+        }
+    }
+
+    /// A month of the user's players' salaries, paid on the first of every
+    /// month.
+    pub fn monthly_wage_bill(&self) -> i64 {
+        let Some(team) = &self.current_team else {
+            return 0;
+        };
+        self.all_players
+            .iter()
+            .filter(|p| team.players.contains(&p.id))
+            .map(|p| p.contract_salary / 12)
+            .sum()
+    }
+
+    /// True once Champions is over and the off-season has run to the end of
+    /// the year. Time stands still until `start_new_season` is called.
+    pub fn is_rollover_due(&self) -> bool {
+        self.tournament_state.is_season_complete()
+            && self.current_date >= Self::season_start(self.current_season + 1)
+    }
+
+    /// Moves the career on to the next season: every player gets a year
+    /// older and the VCT calendar starts over. Contracts that have run out
+    /// send players into free agency unless an AI club renews them. Some
+    /// veterans retire, a new intake of teenagers joins the free-agent pool
+    /// and AI clubs fill any gaps from it. A user's squad left short is
+    /// topped up from whoever is left.
+    pub fn start_new_season(&mut self) -> bool {
+        if !self.is_rollover_due() {
+            return false;
+        }
+
+        let finished_season = self.current_season;
+        let season_stats = aggregate_player_stats(
+            self.match_history
+                .iter()
+                .filter(|result| result.date.year() == finished_season as i32),
+        );
+
+        let records: Vec<(Uuid, f32)> = self
+            .tournament_state
+            .championship_standings
+            .iter()
+            .filter(|s| s.wins + s.losses > 0)
+            .map(|s| (s.team_id, s.wins as f32 / (s.wins + s.losses) as f32))
+            .collect();
+
+        self.current_season += 1;
+        self.current_date = self.current_date.max(Self::season_start(self.current_season));
+        self.tournament_state = self.tournament_state.next_season();
+        self.update_schedule();
+        self.return_loans();
+
         for player in &mut self.all_players {
-            player.daily_update();
+            player.age = player.age.saturating_add(1);
+            age_player(player, &mut self.rng);
+        }
+        self.pay_ai_clubs();
+        self.expire_contracts(&records);
+        self.retire_players(finished_season, &season_stats);
+        self.youth_intake();
+        self.ai_transfer_window(&records);
+        self.fill_ai_rosters();
+        self.fill_user_roster();
+        true
+    }
+
+    /// How an AI club approaches the new season, given last season's record.
+    /// Clubs without one carry on as they are.
+    fn club_strategy(records: &[(Uuid, f32)], team_id: Uuid) -> ClubStrategy {
+        records
+            .iter()
+            .find(|(id, _)| *id == team_id)
+            .map_or(ClubStrategy::Contend, |&(_, win_rate)| ClubStrategy::for_season(win_rate))
+    }
+
+    /// A season's sponsorship and streaming income for every AI club, less
+    /// its wage bill.
+    fn pay_ai_clubs(&mut self) {
+        for team in &mut self.all_teams {
+            let wages: i64 = self
+                .all_players
+                .iter()
+                .filter(|p| team.players.contains(&p.id))
+                .map(|p| p.contract_salary)
+                .sum();
+            team.budget += AI_SEASON_INCOME - wages;
+        }
+    }
+
+    /// The off-season transfer window, with AI clubs taking turns in random
+    /// order. Rebuilding clubs let their veterans go; clubs that are active
+    /// in the window make at most one signing, and every club trims its
+    /// squad back to size.
+    fn ai_transfer_window(&mut self, records: &[(Uuid, f32)]) {
+        let mut order: Vec<Uuid> = self.all_teams.iter().map(|t| t.id).collect();
+        order.shuffle(&mut self.rng);
+        for team_id in order {
+            let strategy = Self::club_strategy(records, team_id);
+            if strategy == ClubStrategy::Rebuild {
+                self.release_veterans(team_id);
+            }
+            if self.rng.gen_bool(WINDOW_ACTIVITY_CHANCE) {
+                self.ai_sign_player(team_id, strategy);
+            }
+            self.trim_ai_squad(team_id, strategy);
         }
     }
-} 
\ No newline at end of file
+
+    /// Looks for the signing that would do the most for an AI club: someone
+    /// clearly better than the weakest of its first five, or who plays a
+    /// role nobody in the squad does. Targets come from other AI clubs at
+    /// their asking price, or from the free-agent pool, and have to fit the
+    /// club's budget.
+    fn ai_sign_player(&mut self, team_id: Uuid, strategy: ClubStrategy) {
+        let Some(team) = self.all_teams.iter().find(|t| t.id == team_id) else {
+            return;
+        };
+        let squad = ranked_squad(team, &self.all_players, strategy);
+        let weakest = squad.get(4).map_or(0, |p| strategy.player_value(p));
+        let missing = missing_roles(team, &self.all_players);
+        let spend = (team.budget as f64 * MAX_SPEND_SHARE) as i64;
+        let unavailable: HashSet<Uuid> = self
+            .loans
+            .iter()
+            .map(|loan| loan.player_id)
+            .chain(self.current_team.iter().flat_map(|t| t.players.iter().copied()))
+            .chain(team.players.iter().copied())
+            .collect();
+
+        let target = self
+            .all_players
+            .iter()
+            .filter(|p| !unavailable.contains(&p.id))
+            .filter_map(|p| {
+                let role_bonus = if missing.contains(&p.preferred_role) { SIGNING_MARGIN } else { 0 };
+                let value = strategy.player_value(p) + role_bonus;
+                if value < weakest + SIGNING_MARGIN {
+                    return None;
+                }
+                let seller = self.all_teams.iter().find(|t| t.players.contains(&p.id));
+                let fee = seller.map_or(0, |seller| asking_price(p, seller, &self.all_players, self.current_date));
+                (fee <= spend).then_some((p.id, seller.map(|t| t.id), fee, value))
+            })
+            .max_by_key(|&(_, _, fee, value)| (value, std::cmp::Reverse(fee)));
+        let Some((player_id, seller_id, fee, _)) = target else {
+            return;
+        };
+
+        let seller_name = match self.all_teams.iter_mut().find(|t| Some(t.id) == seller_id) {
+            Some(seller) => {
+                seller.remove_player(player_id);
+                seller.budget += fee;
+                Some(seller.name.clone())
+            }
+            None => None,
+        };
+        let Some(team) = self.all_teams.iter_mut().find(|t| t.id == team_id) else {
+            return;
+        };
+        team.budget -= fee;
+        team.add_player(player_id);
+        let team_name = team.name.clone();
+
+        let years = self.rng.gen_range(1..=3);
+        let Some(player) = self.all_players.iter_mut().find(|p| p.id == player_id) else {
+            return;
+        };
+        player.contract_expires = Some(Self::season_start(self.current_season + years));
+        player.starter_guarantee = false;
+        let name = player.name.clone();
+
+        match seller_name {
+            Some(seller_name) => {
+                self.record_transfer(player_id, seller_id, Some(team_id), fee, TransferKind::Transfer);
+                self.post_news(format!("{} sign {} from {} for ${}", team_name, name, seller_name, fee));
+            }
+            None => self.record_transfer(player_id, None, Some(team_id), 0, TransferKind::FreeSigning),
+        }
+    }
+
+    /// A rebuilding club releases its players past 28 other than its three
+    /// most valuable.
+    fn release_veterans(&mut self, team_id: Uuid) {
+        let Some(team) = self.all_teams.iter().find(|t| t.id == team_id) else {
+            return;
+        };
+        let veterans: Vec<Uuid> = ranked_squad(team, &self.all_players, ClubStrategy::Rebuild)
+            .into_iter()
+            .skip(3)
+            .filter(|p| p.age > 28)
+            .map(|p| p.id)
+            .collect();
+        for player_id in veterans {
+            self.ai_release(team_id, player_id);
+        }
+    }
+
+    /// Releases the least valuable players from an AI squad that has grown
+    /// past `AI_SQUAD_SIZE`.
+    fn trim_ai_squad(&mut self, team_id: Uuid, strategy: ClubStrategy) {
+        let Some(team) = self.all_teams.iter().find(|t| t.id == team_id) else {
+            return;
+        };
+        let surplus: Vec<Uuid> = ranked_squad(team, &self.all_players, strategy)
+            .into_iter()
+            .filter(|p| !self.loans.iter().any(|loan| loan.player_id == p.id))
+            .skip(AI_SQUAD_SIZE)
+            .map(|p| p.id)
+            .collect();
+        for player_id in surplus {
+            self.ai_release(team_id, player_id);
+        }
+    }
+
+    /// An AI club releases a player, paying off the rest of their contract.
+    /// Players on loan from the user's team are never released.
+    fn ai_release(&mut self, team_id: Uuid, player_id: Uuid) {
+        if self.loans.iter().any(|loan| loan.player_id == player_id) {
+            return;
+        }
+        let Some(team) = self.all_teams.iter_mut().find(|t| t.id == team_id) else {
+            return;
+        };
+        let Some(player) = self.all_players.iter_mut().find(|p| p.id == player_id) else {
+            return;
+        };
+        let payout = termination_payout(player, self.current_date);
+        team.budget -= payout;
+        team.remove_player(player_id);
+        player.contract_expires = None;
+        player.starter_guarantee = false;
+        self.record_transfer(player_id, Some(team_id), None, payout, TransferKind::Release);
+    }
+
+    /// Contracts that ran out with the old season end here. AI clubs offer
+    /// new deals to the players they still rate; everyone else, including
+    /// all of the user's players who weren't renewed, becomes a free agent.
+    fn expire_contracts(&mut self, records: &[(Uuid, f32)]) {
+        let date = self.current_date;
+        let expired: Vec<Uuid> = self
+            .all_players
+            .iter()
+            .filter(|p| p.contract_expires.is_some_and(|expires| expires <= date))
+            .map(|p| p.id)
+            .collect();
+
+        for player_id in expired {
+            let renew_chance = self
+                .all_teams
+                .iter()
+                .find(|t| t.players.contains(&player_id))
+                .zip(self.get_player_by_id(player_id))
+                .map_or(0.0, |(team, player)| {
+                    Self::club_strategy(records, team.id).renewal_chance(player, team, &self.all_players)
+                });
+            let Some(player) = self.all_players.iter_mut().find(|p| p.id == player_id) else {
+                continue;
+            };
+            if self.rng.gen_bool(renew_chance) {
+                let years = self.rng.gen_range(1..=3);
+                player.contract_expires = Some(Self::season_start(self.current_season + years));
+                continue;
+            }
+
+            player.contract_expires = None;
+            player.starter_guarantee = false;
+            player.transfer_listed = false;
+            let name = player.name.clone();
+            let team = self
+                .current_team
+                .iter_mut()
+                .chain(self.all_teams.iter_mut())
+                .find(|t| t.players.contains(&player_id));
+            if let Some(team) = team {
+                team.remove_player(player_id);
+                let headline = format!("{} leaves {} as a free agent", name, team.name);
+                self.post_news(headline);
+            }
+        }
+    }
+
+    /// Decides who retires after `season`. Retirees leave their team and
+    /// move to `retired_players` with their career numbers, and each one
+    /// makes the news.
+    fn retire_players(&mut self, season: u32, season_stats: &[PlayerSeasonStats]) {
+        let mut retiring = HashSet::new();
+        for player in &self.all_players {
+            let stats = season_stats.iter().find(|s| s.player_id == player.id);
+            let years_left = player.contract_years_left(self.current_date);
+            if self.rng.gen_bool(retirement_chance(player, years_left, stats)) {
+                retiring.insert(player.id);
+            }
+        }
+        if retiring.is_empty() {
+            return;
+        }
+
+        let career_stats = aggregate_player_stats(&self.match_history);
+        let (retired, active): (Vec<Player>, Vec<Player>) = std::mem::take(&mut self.all_players)
+            .into_iter()
+            .partition(|p| retiring.contains(&p.id));
+        self.all_players = active;
+
+        for player in retired {
+            let team = self
+                .current_team
+                .iter_mut()
+                .chain(self.all_teams.iter_mut())
+                .find(|t| t.players.contains(&player.id));
+            let headline = match team {
+                Some(team) => {
+                    team.remove_player(player.id);
+                    format!("{}'s {} retires at {}", team.name, player.name, player.age)
+                }
+                None => format!("{} retires at {}", player.name, player.age),
+            };
+            self.post_news(headline);
+
+            let career = career_stats
+                .iter()
+                .find(|s| s.player_id == player.id)
+                .cloned()
+                .unwrap_or_else(|| PlayerSeasonStats::new(player.id));
+            self.retired_players.push(RetiredPlayer { player, season, career });
+        }
+    }
+
+    /// Tops AI rosters back up to five from the free-agent pool, best
+    /// players first, though a club missing a role takes the best player
+    /// who fills it.
+    fn fill_ai_rosters(&mut self) {
+        let mut free_agents: Vec<&Player> = self.free_agents().collect();
+        free_agents.sort_by_key(|p| std::cmp::Reverse(p.current_ability));
+        let mut pool: Vec<(Uuid, AgentRole)> = free_agents.into_iter().map(|p| (p.id, p.preferred_role)).collect();
+
+        let mut signings = Vec::new();
+        for team in &mut self.all_teams {
+            while team.players.len() < MIN_ROSTER_SIZE && !pool.is_empty() {
+                let missing = missing_roles(team, &self.all_players);
+                let index = pool.iter().position(|(_, role)| missing.contains(role)).unwrap_or(0);
+                let (player_id, _) = pool.remove(index);
+                team.add_player(player_id);
+                signings.push((player_id, team.id));
+                if let Some(player) = self.all_players.iter_mut().find(|p| p.id == player_id) {
+                    let years = self.rng.gen_range(1..=3);
+                    player.contract_expires = Some(Self::season_start(self.current_season + years));
+                }
+            }
+        }
+        for (player_id, team_id) in signings {
+            self.record_transfer(player_id, None, Some(team_id), 0, TransferKind::FreeSigning);
+        }
+    }
+
+    /// Brings the user's squad back up to `MIN_ROSTER_SIZE` with the best
+    /// free agents the AI clubs left, on one-year deals, so the team can
+    /// still field five. Each signing makes the news.
+    fn fill_user_roster(&mut self) {
+        let Some(team) = &self.current_team else {
+            return;
+        };
+        let short = MIN_ROSTER_SIZE.saturating_sub(team.players.len());
+        let mut free_agents: Vec<&Player> = self.free_agents().collect();
+        free_agents.sort_by_key(|p| std::cmp::Reverse(p.current_ability));
+        let signings: Vec<Uuid> = free_agents.into_iter().take(short).map(|p| p.id).collect();
+
+        for player_id in signings {
+            let Some(team) = &mut self.current_team else {
+                return;
+            };
+            team.add_player(player_id);
+            let (team_id, team_name) = (team.id, team.name.clone());
+            let Some(player) = self.all_players.iter_mut().find(|p| p.id == player_id) else {
+                continue;
+            };
+            player.contract_expires = Some(Self::season_start(self.current_season + 1));
+            let headline = format!("{} are short of players and sign {} on a one-year deal", team_name, player.name);
+            self.record_transfer(player_id, None, Some(team_id), 0, TransferKind::FreeSigning);
+            self.post_news(headline);
+        }
+    }
+
+    pub fn post_news(&mut self, headline: String) {
+        self.news.push(NewsItem::new(self.current_date, headline));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn career() -> GameState {
+        let mut state = GameState::with_seed(7);
+        state.initialize_with_team("Test Esports".to_string());
+        state
+    }
+
+    fn user_players(state: &GameState) -> Vec<Uuid> {
+        state.current_team.as_ref().unwrap().players.clone()
+    }
+
+    /// Skips ahead to the last day of the month and plays into the next.
+    /// Returns how much the budget went down.
+    fn month_end(state: &mut GameState, month: u32) -> i64 {
+        let next_month = Utc.with_ymd_and_hms(state.current_season as i32, month + 1, 1, 0, 0, 0).unwrap();
+        state.current_date = next_month - Duration::days(2);
+        state.advance_day();
+        let before = state.budget;
+        state.advance_day();
+        assert_eq!(state.current_date.day(), 1);
+        before - state.budget
+    }
+
+    #[test]
+    fn wages_come_out_on_the_first_of_the_month() {
+        let mut state = career();
+        let bill = state.monthly_wage_bill();
+        assert!(bill > 0);
+        assert_eq!(month_end(&mut state, 1), bill);
+
+        // A renewal on a bigger salary shows up in the next month's bill
+        let player_id = user_players(&state)[0];
+        let old_salary = state.get_player_by_id(player_id).unwrap().contract_salary;
+        state.all_players.iter_mut().find(|p| p.id == player_id).unwrap().contract_expires =
+            Some(GameState::season_start(state.current_season + 1));
+        let mut negotiation = state.open_negotiation(player_id).unwrap();
+        let offer = ContractOffer {
+            salary: old_salary * 2,
+            ..negotiation.demand.clone()
+        };
+        assert_eq!(state.submit_offer(&mut negotiation, offer), Some(NegotiationResponse::Accepted));
+
+        let raise = old_salary * 2 / 12 - old_salary / 12;
+        assert_eq!(state.monthly_wage_bill(), bill + raise);
+        assert_eq!(month_end(&mut state, 2), bill + raise);
+    }
+    /// Puts a free agent straight into the user's squad, so a player can be
+    /// let go without dropping below five. Returns the player.
+    fn sign_sixth_player(state: &mut GameState) -> Uuid {
+        let player_id = state.free_agents().next().unwrap().id;
+        state.current_team.as_mut().unwrap().add_player(player_id);
+        player_id
+    }
+
+    /// Plays the calendar out to the end of the year, skipping the matches.
+    fn end_of_season(state: &mut GameState) {
+        state.tournament_state.events_completed.push(VCTEvent::ChampionsParis);
+        state.current_date = GameState::season_start(state.current_season + 1);
+        assert!(state.is_rollover_due());
+    }
+
+    #[test]
+    fn a_squad_left_short_at_rollover_is_topped_up() {
+        let mut state = career();
+        let players = user_players(&state);
+        // Every contract runs out, and the user doesn't renew any
+        for player in state.all_players.iter_mut().filter(|p| players.contains(&p.id)) {
+            player.contract_expires = Some(GameState::season_start(state.current_season + 1));
+        }
+        end_of_season(&mut state);
+        let news = state.news.len();
+
+        assert!(state.start_new_season());
+        let squad = user_players(&state);
+        assert_eq!(squad.len(), MIN_ROSTER_SIZE);
+        assert_eq!(state.current_team.as_ref().unwrap().starting_lineup.len(), 5);
+        for player_id in &squad {
+            let player = state.get_player_by_id(*player_id).unwrap();
+            assert_eq!(player.contract_expires, Some(GameState::season_start(state.current_season + 1)));
+            assert!(state.transfers.iter().any(|t| t.player_id == *player_id && t.kind == TransferKind::FreeSigning));
+            assert!(state.news[news..].iter().any(|n| n.headline.contains(&player.name)));
+        }
+    }
+    #[test]
+    fn free_agents_sign_on_personal_terms_without_a_fee() {
+        let mut state = career();
+        let player_id = state.free_agents().next().unwrap().id;
+        assert!(state.open_free_agent_negotiation(user_players(&state)[0]).is_none());
+
+        let mut negotiation = state.open_free_agent_negotiation(player_id).unwrap();
+        let offer = negotiation.demand.clone();
+        let budget = state.budget;
+        assert_eq!(state.submit_offer(&mut negotiation, offer.clone()), Some(NegotiationResponse::Accepted));
+
+        assert!(user_players(&state).contains(&player_id));
+        assert_eq!(state.budget, budget - offer.signing_bonus);
+        let player = state.get_player_by_id(player_id).unwrap();
+        assert_eq!(player.contract_salary, offer.salary);
+        assert_eq!(player.contract_expires, Some(GameState::season_start(state.current_season + offer.length as u32)));
+        let record = state.transfers.last().unwrap();
+        assert_eq!((record.player_id, record.fee, record.kind), (player_id, 0, TransferKind::FreeSigning));
+
+        // Talks with someone who has signed elsewhere come to nothing
+        let other_id = state.free_agents().next().unwrap().id;
+        let mut negotiation = state.open_free_agent_negotiation(other_id).unwrap();
+        state.all_teams[0].add_player(other_id);
+        let offer = negotiation.demand.clone();
+        assert_eq!(state.submit_offer(&mut negotiation, offer), None);
+    }
+    #[test]
+    fn bids_that_cant_be_made_say_why() {
+        let mut state = career();
+        let target = state.all_teams[0].players[0];
+        assert_eq!(state.bid_for_player(target, 0), Err(BidError::InvalidFee));
+        assert_eq!(state.bid_for_player(target, state.budget + 1), Err(BidError::CantAfford));
+        let free_agent = state.free_agents().next().unwrap().id;
+        assert_eq!(state.bid_for_player(free_agent, 1_000), Err(BidError::NotAtAClub));
+        assert!(state.bid_for_player(target, 1_000).is_ok());
+
+        sign_sixth_player(&mut state);
+        let loaned = user_players(&state)[0];
+        state.loan_out(loaned).unwrap();
+        assert_eq!(state.bid_for_player(loaned, 1_000), Err(BidError::OnLoan));
+
+        state.current_team = None;
+        assert_eq!(state.bid_for_player(target, 1_000), Err(BidError::NoTeam));
+    }
+    #[test]
+    fn a_transfer_pays_the_selling_club_and_goes_in_the_history() {
+        let mut state = career();
+        let club_id = state.all_teams[0].id;
+        let player_id = state.all_teams[0].players[0];
+
+        // A lowball bid is turned down; a closer one gets the asking price back
+        let player = state.get_player_by_id(player_id).unwrap();
+        let price = asking_price(player, &state.all_teams[0], &state.all_players, state.current_date);
+        assert!(price <= state.budget);
+        assert_eq!(state.bid_for_player(player_id, price / 2), Ok(TransferResponse::Rejected));
+        assert_eq!(state.bid_for_player(player_id, price * 4 / 5), Ok(TransferResponse::Countered(price)));
+        assert_eq!(state.bid_for_player(player_id, price), Ok(TransferResponse::Accepted));
+
+        let mut negotiation = state.open_transfer_negotiation(player_id, price).unwrap();
+        let offer = negotiation.demand.clone();
+        let (budget, club_budget) = (state.budget, state.all_teams[0].budget);
+        assert_eq!(state.submit_offer(&mut negotiation, offer.clone()), Some(NegotiationResponse::Accepted));
+
+        assert_eq!(state.budget, budget - price - offer.signing_bonus);
+        let club = state.all_teams.iter().find(|t| t.id == club_id).unwrap();
+        assert_eq!(club.budget, club_budget + price);
+        assert!(!club.players.contains(&player_id));
+        assert!(user_players(&state).contains(&player_id));
+        let user_id = state.current_team.as_ref().unwrap().id;
+        let record = state.transfers.iter().find(|t| t.player_id == player_id).unwrap();
+        assert_eq!(
+            (record.from_team, record.to_team, record.fee, record.kind),
+            (Some(club_id), Some(user_id), price, TransferKind::Transfer)
+        );
+    }
+    #[test]
+    fn releasing_a_player_pays_off_the_rest_of_their_contract() {
+        let mut state = career();
+        let player_id = sign_sixth_player(&mut state);
+        let player = state.all_players.iter_mut().find(|p| p.id == player_id).unwrap();
+        player.contract_salary = 120_000;
+        player.contract_expires = Some(GameState::season_start(state.current_season + 2));
+        let budget = state.budget;
+
+        // Two years left on 120k a year
+        assert_eq!(state.release_player(player_id), Some(240_000));
+        assert_eq!(state.budget, budget - 240_000);
+        assert!(state.free_agents().any(|p| p.id == player_id));
+        let record = state.transfers.last().unwrap();
+        assert_eq!((record.player_id, record.fee, record.kind), (player_id, 240_000, TransferKind::Release));
+    }
+
+    #[test]
+    fn a_squad_of_five_cant_lose_anyone() {
+        let mut state = career();
+        let players = user_players(&state);
+        assert_eq!(players.len(), MIN_ROSTER_SIZE);
+        let budget = state.budget;
+
+        assert_eq!(state.release_player(players[0]), None);
+        assert_eq!(state.loan_out(players[1]), None);
+        assert_eq!(user_players(&state), players);
+        assert_eq!(state.budget, budget);
+        assert!(state.loans.is_empty());
+    }
+
+    #[test]
+    fn loaned_players_come_back_at_rollover() {
+        let mut state = career();
+        let player_id = sign_sixth_player(&mut state);
+        let player = state.all_players.iter_mut().find(|p| p.id == player_id).unwrap();
+        player.age = 20;
+        player.contract_expires = Some(GameState::season_start(state.current_season + 3));
+
+        let club_id = state.loan_out(player_id).unwrap();
+        assert!(!user_players(&state).contains(&player_id));
+        assert!(state.all_teams.iter().find(|t| t.id == club_id).unwrap().players.contains(&player_id));
+
+        end_of_season(&mut state);
+        assert!(state.start_new_season());
+        assert!(user_players(&state).contains(&player_id));
+        assert!(!state.all_teams.iter().find(|t| t.id == club_id).unwrap().players.contains(&player_id));
+        assert!(state.loans.is_empty());
+    }
+
+    #[test]
+    fn selling_a_popular_player_unsettles_the_squad() {
+        for popular in [true, false] {
+            let mut state = career();
+            let bench_player = sign_sixth_player(&mut state);
+            let starter = state.current_team.as_ref().unwrap().starting_lineup[0];
+            let sold = if popular { starter } else { bench_player };
+            for player in state.all_players.iter_mut() {
+                player.morale = Morale::Good;
+                player.attributes.communication = if player.id == starter { 20 } else { 1 };
+            }
+            // Make sure the bench player isn't the best in the squad either
+            state.all_players.iter_mut().find(|p| p.id == bench_player).unwrap().current_ability = 1;
+
+            let club = &state.all_teams[0];
+            let bid_id = Uuid::from_u128(1);
+            state.incoming_bids.push(IncomingBid {
+                id: bid_id,
+                player_id: sold,
+                team_id: club.id,
+                fee: club.budget,
+                expires: state.current_date + Duration::days(BID_VALID_DAYS),
+            });
+            assert!(state.accept_bid(bid_id));
+
+            let expected = if popular { Morale::Average } else { Morale::Good };
+            for player_id in user_players(&state) {
+                assert_eq!(state.get_player_by_id(player_id).unwrap().morale, expected);
+            }
+            let unsettled = state.news.iter().any(|n| n.headline.contains("dressing room unsettled"));
+            assert_eq!(unsettled, popular);
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+
+/// A headline from around the scene.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewsItem {
+    pub date: DateTime<Utc>,
+    pub headline: String,
+}
+
+impl NewsItem {
+    pub fn new(date: DateTime<Utc>, headline: String) -> Self {
+        Self { date, headline }
+    }
+}
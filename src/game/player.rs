@@ -1,6 +1,11 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
+use chrono::{DateTime, Datelike, Utc};
 use rand::Rng;
+use crate::game::agent::Agent;
+use crate::game::injury::Injury;
+use crate::game::rng::random_uuid;
+use crate::game::training::TrainingSchedule;
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum AgentRole {
@@ -40,8 +45,7 @@ pub struct PlayerAttributes {
 }
 
 impl PlayerAttributes {
-    pub fn generate_random() -> Self {
-        let mut rng = rand::thread_rng();
+    pub fn generate_random<R: Rng + ?Sized>(rng: &mut R) -> Self {
         Self {
             aim: rng.gen_range(8..=18),
             utility_usage: rng.gen_range(8..=18),
@@ -57,12 +61,46 @@ impl PlayerAttributes {
         }
     }
 
+    /// Teenagers come through raw; by their early twenties players are much
+    /// closer to the finished article.
+    pub fn generate_for_age<R: Rng + ?Sized>(age: u8, rng: &mut R) -> Self {
+        let (low, high) = match age {
+            0..=17 => (4, 12),
+            18..=19 => (6, 14),
+            20..=22 => (7, 16),
+            _ => (8, 18),
+        };
+        Self {
+            aim: rng.gen_range(low..=high),
+            utility_usage: rng.gen_range(low..=high),
+            movement: rng.gen_range(low..=high),
+            clutch_potential: rng.gen_range(low..=high),
+            game_sense: rng.gen_range(low..=high),
+            communication: rng.gen_range(low..=high),
+            composure: rng.gen_range(low..=high),
+            aggression: rng.gen_range(low..=high),
+            adaptability: rng.gen_range(low..=high),
+            stamina: rng.gen_range(low + 2..=18),
+            natural_fitness: rng.gen_range(low + 2..=18),
+        }
+    }
+
+    /// Overall rating on a finer 1-200 scale, used as a player's hidden
+    /// current ability.
+    pub fn ability(&self) -> u8 {
+        let technical = (self.aim + self.utility_usage + self.movement + self.clutch_potential) as f32 / 4.0;
+        let mental = (self.game_sense + self.communication + self.composure + self.aggression + self.adaptability) as f32 / 5.0;
+        let physical = (self.stamina + self.natural_fitness) as f32 / 2.0;
+
+        (technical * 4.0 + mental * 5.0 + physical).round().clamp(1.0, 200.0) as u8
+    }
+
     pub fn overall_rating(&self) -> u8 {
         let technical = (self.aim + self.utility_usage + self.movement + self.clutch_potential) / 4;
         let mental = (self.game_sense + self.communication + self.composure + self.aggression + self.adaptability) / 5;
         let physical = (self.stamina + self.natural_fitness) / 2;
         
-        (technical * 4 + mental * 5 + physical * 1) / 10
+        (technical * 4 + mental * 5 + physical) / 10
     }
 }
 
@@ -72,6 +110,12 @@ pub struct AgentProficiency {
     pub proficiency: u8, // 1-20 scale
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentMastery {
+    pub agent: Agent,
+    pub proficiency: u8, // 1-20 scale
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Player {
     pub id: Uuid,
@@ -81,16 +125,36 @@ pub struct Player {
     pub attributes: PlayerAttributes,
     pub preferred_role: AgentRole,
     pub agent_proficiencies: Vec<AgentProficiency>,
+    pub agent_pool: Vec<AgentMastery>,
     pub morale: Morale,
     pub contract_salary: i64,
-    pub contract_length: u8, // years remaining
+    pub contract_expires: Option<DateTime<Utc>>, // None for free agents
+    pub starter_guarantee: bool, // Promised a place in the starting lineup
+    pub transfer_listed: bool,
     pub market_value: i64,
     pub training_happiness: i8, // -10 to +10
+    pub current_ability: u8, // Hidden, 1-200 scale
+    pub potential_ability: u8, // Hidden ceiling for current ability, 1-200 scale
+    pub training: TrainingSchedule,
+    pub fatigue: u8, // 0-100
+    pub break_days: u8, // Days left on a forced break after burning out
+    pub injury: Option<Injury>,
+    pub maps_this_week: u8, // Match experience for this week's training
 }
 
 impl Player {
-    pub fn new(name: String) -> Self {
-        let mut rng = rand::thread_rng();
+    pub fn new<R: Rng + ?Sized>(name: String, rng: &mut R) -> Self {
+        let age = rng.gen_range(18..=28);
+        Self::with_age(name, age, rng)
+    }
+
+    /// A teenager fresh out of the amateur scene.
+    pub fn new_youth<R: Rng + ?Sized>(name: String, rng: &mut R) -> Self {
+        let age = rng.gen_range(16..=18);
+        Self::with_age(name, age, rng)
+    }
+
+    fn with_age<R: Rng + ?Sized>(name: String, age: u8, rng: &mut R) -> Self {
         let preferred_role = match rng.gen_range(0..4) {
             0 => AgentRole::Duelist,
             1 => AgentRole::Initiator,
@@ -98,37 +162,89 @@ impl Player {
             _ => AgentRole::Sentinel,
         };
 
-        let attributes = PlayerAttributes::generate_random();
+        let attributes = PlayerAttributes::generate_for_age(age, rng);
         let market_value = Self::calculate_market_value(&attributes);
+        let current_ability = attributes.ability();
+        let potential_ability = Self::generate_potential_ability(age, current_ability, rng);
+        let agent_proficiencies = Self::generate_agent_proficiencies(preferred_role, rng);
+        let agent_pool = Self::generate_agent_pool(preferred_role, &agent_proficiencies, rng);
 
         Self {
-            id: Uuid::new_v4(),
+            id: random_uuid(rng),
             name,
-            age: rng.gen_range(18..=28),
+            age,
             nationality: "USA".to_string(), // Simplified for MVP
             preferred_role,
-            agent_proficiencies: Self::generate_agent_proficiencies(preferred_role),
+            agent_proficiencies,
+            agent_pool,
             attributes,
             morale: Morale::Average,
             contract_salary: market_value / 5, // Rough salary calculation
-            contract_length: rng.gen_range(1..=4),
+            contract_expires: None,
+            starter_guarantee: false,
+            transfer_listed: false,
             market_value,
             training_happiness: 0,
+            current_ability,
+            potential_ability,
+            training: TrainingSchedule::new(),
+            fatigue: 0,
+            break_days: 0,
+            injury: None,
+            maps_this_week: 0,
+        }
+    }
+
+    pub fn generate_random<R: Rng + ?Sized>(name: String, rng: &mut R) -> Self {
+        Self::new(name, rng)
+    }
+
+    /// Younger players have more room left to grow, and the odd teenager is
+    /// a wonderkid.
+    pub(crate) fn generate_potential_ability<R: Rng + ?Sized>(age: u8, current_ability: u8, rng: &mut R) -> u8 {
+        let mut headroom: u16 = match age {
+            0..=18 => rng.gen_range(20..=70),
+            19..=20 => rng.gen_range(15..=50),
+            21..=24 => rng.gen_range(5..=30),
+            _ => rng.gen_range(0..=15),
+        };
+        if age <= 19 && rng.gen_bool(0.05) {
+            headroom += 40;
         }
+        (current_ability as u16 + headroom).min(200) as u8
     }
 
-    pub fn generate_random(name: String) -> Self {
-        Self::new(name)
+    /// Brings current ability and market value in line with the attributes.
+    pub fn update_ratings(&mut self) {
+        self.current_ability = self.attributes.ability();
+        self.market_value = Self::calculate_market_value(&self.attributes);
     }
 
     fn calculate_market_value(attributes: &PlayerAttributes) -> i64 {
         let overall = attributes.overall_rating() as i64;
-        // Base value between $50k and $500k based on overall rating
-        50_000 + (overall - 8) * 45_000
+        // Base value between $50k and $500k based on overall rating, with
+        // raw youngsters bottoming out at $25k
+        (50_000 + (overall - 8) * 45_000).max(25_000)
+    }
+
+    /// A made-up in-game name for newly generated players.
+    pub fn random_handle<R: Rng + ?Sized>(rng: &mut R) -> String {
+        const STARTS: [&str; 16] = [
+            "Ze", "Kai", "Nyx", "Vex", "Ro", "Jin", "Sly", "Ash",
+            "Tek", "Mir", "Zy", "Kro", "Lux", "Fen", "Dra", "Qui",
+        ];
+        const ENDS: [&str; 16] = [
+            "ro", "x", "th", "zen", "ko", "nix", "ra", "vo",
+            "lyn", "sh", "tic", "mo", "rix", "do", "ne", "z",
+        ];
+        let mut handle = format!("{}{}", STARTS[rng.gen_range(0..STARTS.len())], ENDS[rng.gen_range(0..ENDS.len())]);
+        if rng.gen_bool(0.3) {
+            handle.push_str(&rng.gen_range(1..100).to_string());
+        }
+        handle
     }
 
-    fn generate_agent_proficiencies(preferred_role: AgentRole) -> Vec<AgentProficiency> {
-        let mut rng = rand::thread_rng();
+    fn generate_agent_proficiencies<R: Rng + ?Sized>(preferred_role: AgentRole, rng: &mut R) -> Vec<AgentProficiency> {
         let mut proficiencies = Vec::new();
 
         // High proficiency in preferred role
@@ -151,6 +267,60 @@ impl Player {
         proficiencies
     }
 
+    /// Every agent starts a little below the player's proficiency in its role,
+    /// with one or two mains in the preferred role sitting above it.
+    pub(crate) fn generate_agent_pool<R: Rng + ?Sized>(
+        preferred_role: AgentRole,
+        role_proficiencies: &[AgentProficiency],
+        rng: &mut R,
+    ) -> Vec<AgentMastery> {
+        let mut pool: Vec<AgentMastery> = Agent::ALL
+            .iter()
+            .map(|&agent| {
+                let role_proficiency = role_proficiencies
+                    .iter()
+                    .find(|p| p.role == agent.role())
+                    .map(|p| p.proficiency)
+                    .unwrap_or(5);
+                AgentMastery {
+                    agent,
+                    proficiency: role_proficiency.saturating_sub(rng.gen_range(0..=6)).max(1),
+                }
+            })
+            .collect();
+
+        let mains: Vec<Agent> = Agent::agents_for_role(preferred_role).collect();
+        for _ in 0..rng.gen_range(1..=2) {
+            let main = mains[rng.gen_range(0..mains.len())];
+            if let Some(mastery) = pool.iter_mut().find(|m| m.agent == main) {
+                let role_proficiency = role_proficiencies
+                    .iter()
+                    .find(|p| p.role == preferred_role)
+                    .map(|p| p.proficiency)
+                    .unwrap_or(5);
+                mastery.proficiency = (role_proficiency + rng.gen_range(0..=3)).min(20);
+            }
+        }
+
+        pool
+    }
+
+    pub fn get_agent_proficiency(&self, agent: Agent) -> u8 {
+        self.agent_pool
+            .iter()
+            .find(|m| m.agent == agent)
+            .map(|m| m.proficiency)
+            .unwrap_or(5)
+    }
+
+    /// The player's strongest agents, best first.
+    pub fn best_agents(&self, count: usize) -> Vec<&AgentMastery> {
+        let mut pool: Vec<&AgentMastery> = self.agent_pool.iter().collect();
+        pool.sort_by_key(|m| std::cmp::Reverse(m.proficiency));
+        pool.truncate(count);
+        pool
+    }
+
     pub fn get_role_proficiency(&self, role: AgentRole) -> u8 {
         self.agent_proficiencies
             .iter()
@@ -159,9 +329,35 @@ impl Player {
             .unwrap_or(5)
     }
 
-    pub fn daily_update(&mut self) {
+    /// Seasons left on the contract, counting the current one. Zero once it
+    /// has run out.
+    pub fn contract_years_left(&self, date: DateTime<Utc>) -> u8 {
+        self.contract_expires
+            .map_or(0, |expires| (expires.year() - date.year()).clamp(0, u8::MAX as i32) as u8)
+    }
+
+    /// Renewal talks open once a contract is into its final season.
+    pub fn in_final_year(&self, date: DateTime<Utc>) -> bool {
+        self.contract_years_left(date) == 1
+    }
+
+    /// Whether the player can be picked for a match.
+    pub fn is_available(&self) -> bool {
+        self.break_days == 0 && self.current_injury().is_none()
+    }
+
+    /// The injury or illness keeping the player out, if any.
+    pub fn current_injury(&self) -> Option<&Injury> {
+        self.injury.as_ref().filter(|i| !i.recovered)
+    }
+
+    /// How fresh the player is, the inverse of fatigue.
+    pub fn condition(&self) -> u8 {
+        100 - self.fatigue.min(100)
+    }
+
+    pub fn daily_update<R: Rng + ?Sized>(&mut self, rng: &mut R) {
         // Simple daily morale fluctuation
-        let mut rng = rand::thread_rng();
         if rng.gen_bool(0.1) { // 10% chance of morale change
             match rng.gen_range(0..2) {
                 0 => self.improve_morale(),
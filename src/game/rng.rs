@@ -0,0 +1,16 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use uuid::Uuid;
+
+/// The random number generator every game system draws from. It's saved with
+/// the career, so the same seed and the same inputs always play out the same.
+pub type GameRng = ChaCha8Rng;
+
+pub fn seeded_rng(seed: u64) -> GameRng {
+    GameRng::seed_from_u64(seed)
+}
+
+/// A version 4 UUID drawn from the game RNG rather than the OS.
+pub fn random_uuid<R: Rng + ?Sized>(rng: &mut R) -> Uuid {
+    uuid::Builder::from_random_bytes(rng.gen()).into_uuid()
+}
@@ -0,0 +1,892 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use chrono::{DateTime, Utc};
+use rand::{Rng, SeedableRng};
+use std::fmt;
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use crate::game::player::{AgentProficiency, AgentRole, Player, PlayerAttributes};
+use crate::game::rng::{seeded_rng, GameRng};
+use crate::game::scouting::ScoutingNetwork;
+use crate::game::tactics::TacticalSetup;
+use crate::game::training::TrainingSchedule;
+use crate::game::team::Team;
+use crate::game::GameState;
+
+/// Current save file schema. Bump this and add a step to `migrate` whenever a
+/// change to `GameState` can't be read from an older save as-is.
+pub const SAVE_VERSION: u32 = 14;
+
+/// Slot the latest autosave is written to. Older autosaves are kept as
+/// `autosave-1`, `autosave-2`, ... up to the configured number of backups.
+/// Manual saves can't use any of these names.
+pub const AUTOSAVE_SLOT: &str = "autosave";
+
+#[derive(Debug)]
+pub enum SaveError {
+    Io(std::io::Error),
+    Format(serde_json::Error),
+    UnsupportedVersion(u32),
+    InvalidSlotName,
+    ReservedSlotName,
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::Io(err) => write!(f, "could not access save file: {}", err),
+            SaveError::Format(err) => write!(f, "save file is corrupted: {}", err),
+            SaveError::UnsupportedVersion(version) => write!(
+                f,
+                "save file version {} is newer than this game supports ({})",
+                version, SAVE_VERSION
+            ),
+            SaveError::InvalidSlotName => write!(f, "save name must contain letters or numbers"),
+            SaveError::ReservedSlotName => write!(f, "that name is kept for autosaves"),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+impl From<std::io::Error> for SaveError {
+    fn from(err: std::io::Error) -> Self {
+        SaveError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for SaveError {
+    fn from(err: serde_json::Error) -> Self {
+        SaveError::Format(err)
+    }
+}
+
+/// What the load-game browser shows for a save without loading the career.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveSummary {
+    pub slot: String,
+    pub team_name: String,
+    pub season: u32,
+    pub game_date: DateTime<Utc>,
+    pub wins: u32,
+    pub losses: u32,
+    pub saved_at: DateTime<Utc>,
+}
+
+impl SaveSummary {
+    fn from_state(slot: &str, state: &GameState) -> Self {
+        let team = state.current_team.as_ref();
+        Self {
+            slot: slot.to_string(),
+            team_name: team.map(|t| t.name.clone()).unwrap_or_default(),
+            season: state.current_season,
+            game_date: state.current_date,
+            wins: team.map(|t| t.wins).unwrap_or(0),
+            losses: team.map(|t| t.losses).unwrap_or(0),
+            saved_at: Utc::now(),
+        }
+    }
+}
+
+/// Points in the career where the game saves itself.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum AutosaveTrigger {
+    WeekAdvanced,
+    MatchPlayed,
+    SeasonRollover,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutosaveSettings {
+    pub enabled: bool,
+    pub on_week_advance: bool,
+    pub after_match: bool,
+    pub before_season_rollover: bool,
+    pub backups: usize,
+}
+
+impl Default for AutosaveSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            on_week_advance: true,
+            after_match: true,
+            before_season_rollover: true,
+            backups: 3,
+        }
+    }
+}
+
+impl AutosaveSettings {
+    pub fn should_save(&self, trigger: AutosaveTrigger) -> bool {
+        self.enabled
+            && match trigger {
+                AutosaveTrigger::WeekAdvanced => self.on_week_advance,
+                AutosaveTrigger::MatchPlayed => self.after_match,
+                AutosaveTrigger::SeasonRollover => self.before_season_rollover,
+            }
+    }
+}
+
+/// The first line of a save file. The career follows on the next line, so
+/// the load-game browser can show a save without reading the rest.
+#[derive(Serialize, Deserialize)]
+struct SaveHeader {
+    version: u32,
+    summary: SaveSummary,
+}
+
+/// Named save slots stored as JSON files in a data directory.
+pub struct SaveManager {
+    dir: PathBuf,
+}
+
+impl SaveManager {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// The per-user data directory, falling back to `./saves` if the platform
+    /// doesn't have one.
+    pub fn default_dir() -> PathBuf {
+        directories::ProjectDirs::from("", "", "Valorant Manager")
+            .map(|dirs| dirs.data_dir().join("saves"))
+            .unwrap_or_else(|| PathBuf::from("saves"))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn slot_path(&self, slot: &str) -> Result<PathBuf, SaveError> {
+        let slot = slot.trim();
+        if !slot.chars().any(|c| c.is_alphanumeric()) {
+            return Err(SaveError::InvalidSlotName);
+        }
+        Ok(self.dir.join(format!("{}.json", encode_slot_name(slot))))
+    }
+
+    /// Where a slot saved before names were encoded lives, if there is one.
+    /// Old file names that happen to be valid encodings belong to whichever
+    /// slot they decode to.
+    fn legacy_slot_path(&self, slot: &str) -> Option<PathBuf> {
+        let file_name = legacy_file_name(slot);
+        if decode_slot_name(&file_name).is_some() {
+            return None;
+        }
+        Some(self.dir.join(format!("{}.json", file_name))).filter(|path| path.exists())
+    }
+
+    fn existing_slot_path(&self, slot: &str) -> Result<PathBuf, SaveError> {
+        let path = self.slot_path(slot)?;
+        if path.exists() {
+            return Ok(path);
+        }
+        Ok(self.legacy_slot_path(slot).unwrap_or(path))
+    }
+
+    fn is_reserved(slot: &str) -> bool {
+        let slot = slot.trim();
+        slot == AUTOSAVE_SLOT
+            || slot
+                .strip_prefix(AUTOSAVE_SLOT)
+                .and_then(|rest| rest.strip_prefix('-'))
+                .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+    }
+
+    /// Saves to a named slot, replacing whatever was saved under that name.
+    /// The autosave names are refused.
+    pub fn save(&self, slot: &str, state: &GameState) -> Result<SaveSummary, SaveError> {
+        if Self::is_reserved(slot) {
+            return Err(SaveError::ReservedSlotName);
+        }
+        self.write_slot(slot, state)
+    }
+
+    fn write_slot(&self, slot: &str, state: &GameState) -> Result<SaveSummary, SaveError> {
+        let path = self.slot_path(slot)?;
+        fs::create_dir_all(&self.dir)?;
+
+        let header = SaveHeader {
+            version: SAVE_VERSION,
+            summary: SaveSummary::from_state(slot.trim(), state),
+        };
+        let mut contents = serde_json::to_string(&header)?;
+        contents.push('\n');
+        contents.push_str(&serde_json::to_string(state)?);
+        write_atomic(&path, contents.as_bytes())?;
+
+        // The slot now lives under its encoded name
+        if let Some(legacy_path) = self.legacy_slot_path(slot) {
+            fs::remove_file(legacy_path)?;
+        }
+        Ok(header.summary)
+    }
+
+    /// Writes the autosave slot, first shifting the previous autosaves down
+    /// one place and dropping the oldest beyond `backups`.
+    pub fn autosave(&self, state: &GameState, backups: usize) -> Result<SaveSummary, SaveError> {
+        let backup_slot = |n: usize| format!("{}-{}", AUTOSAVE_SLOT, n);
+
+        if backups > 0 && self.slot_path(AUTOSAVE_SLOT)?.exists() {
+            let oldest = self.slot_path(&backup_slot(backups))?;
+            if oldest.exists() {
+                fs::remove_file(oldest)?;
+            }
+            for n in (1..backups).rev() {
+                let from = self.slot_path(&backup_slot(n))?;
+                if from.exists() {
+                    fs::rename(from, self.slot_path(&backup_slot(n + 1))?)?;
+                }
+            }
+            fs::rename(self.slot_path(AUTOSAVE_SLOT)?, self.slot_path(&backup_slot(1))?)?;
+        }
+
+        self.write_slot(AUTOSAVE_SLOT, state)
+    }
+
+    /// Autosaves unless the latest autosave already holds this exact career,
+    /// so a game left idle doesn't push real backups out. Returns `None` if
+    /// nothing was written.
+    pub fn autosave_if_changed(&self, state: &GameState, backups: usize) -> Result<Option<SaveSummary>, SaveError> {
+        let career = serde_json::to_string(state)?;
+        let latest = fs::read_to_string(self.slot_path(AUTOSAVE_SLOT)?).unwrap_or_default();
+        if latest.split_once('\n').is_some_and(|(_, saved)| saved == career) {
+            return Ok(None);
+        }
+        self.autosave(state, backups).map(Some)
+    }
+
+    /// Saves a career kept outside the save directory into `slot`. `state` is
+    /// a bare `GameState` from before save slots existed, and is migrated
+    /// like any other save of that age.
+    pub fn import(&self, slot: &str, mut state: Value) -> Result<SaveSummary, SaveError> {
+        migrate(0, &mut state);
+        let game_state: GameState = serde_json::from_value(state)?;
+        self.save(slot, &game_state)
+    }
+
+    pub fn load(&self, slot: &str) -> Result<GameState, SaveError> {
+        let (_, state) = Self::read_file(&self.existing_slot_path(slot)?)?;
+        Ok(state)
+    }
+
+    pub fn delete(&self, slot: &str) -> Result<(), SaveError> {
+        fs::remove_file(self.existing_slot_path(slot)?)?;
+        Ok(())
+    }
+
+    /// Every readable save in the directory, most recently saved first.
+    /// Files that can't be read are skipped.
+    pub fn list_saves(&self) -> Vec<SaveSummary> {
+        let Ok(entries) = fs::read_dir(&self.dir) else {
+            return Vec::new();
+        };
+
+        let mut saves: Vec<SaveSummary> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().map(|e| e == "json").unwrap_or(false))
+            .filter_map(|path| Self::read_summary(&path).ok())
+            .collect();
+        saves.sort_by_key(|s| std::cmp::Reverse(s.saved_at));
+        saves
+    }
+
+    /// The slot a file holds. This is wherever the file lives now, which
+    /// changes when autosaves are rotated.
+    fn slot_name(path: &Path) -> String {
+        let file_name = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        decode_slot_name(&file_name).unwrap_or(file_name)
+    }
+
+    fn read_summary(path: &Path) -> Result<SaveSummary, SaveError> {
+        let mut line = String::new();
+        BufReader::new(fs::File::open(path)?).read_line(&mut line)?;
+        match serde_json::from_str::<SaveHeader>(&line) {
+            Ok(header) if header.version > SAVE_VERSION => Err(SaveError::UnsupportedVersion(header.version)),
+            Ok(header) => Ok(SaveSummary {
+                slot: Self::slot_name(path),
+                ..header.summary
+            }),
+            // Older saves have no header line to go by
+            Err(_) => Self::read_file(path).map(|(summary, _)| summary),
+        }
+    }
+
+    fn read_file(path: &Path) -> Result<(SaveSummary, GameState), SaveError> {
+        let contents = fs::read_to_string(path)?;
+        let (version, summary, mut state) = match contents.split_once('\n') {
+            Some((header, state)) if !state.trim().is_empty() => {
+                let header: SaveHeader = serde_json::from_str(header)?;
+                (header.version, Some(header.summary), serde_json::from_str(state)?)
+            }
+            _ => Self::parse_single_document(&contents)?,
+        };
+        if version > SAVE_VERSION {
+            return Err(SaveError::UnsupportedVersion(version));
+        }
+
+        migrate(version, &mut state);
+        let game_state: GameState = serde_json::from_value(state)?;
+
+        let slot = Self::slot_name(path);
+        let summary = match summary {
+            Some(summary) => SaveSummary { slot, ..summary },
+            None => SaveSummary::from_state(&slot, &game_state),
+        };
+        Ok((summary, game_state))
+    }
+
+    /// Reads a save from before the header got a line of its own: one JSON
+    /// document holding the version, summary and career, or a bare
+    /// `GameState` from before slots existed.
+    fn parse_single_document(contents: &str) -> Result<(u32, Option<SaveSummary>, Value), SaveError> {
+        let mut file: Value = serde_json::from_str(contents)?;
+        let Some(version) = file.get("version").and_then(Value::as_u64) else {
+            return Ok((0, None, file));
+        };
+        let summary = match file.get_mut("summary").map(Value::take) {
+            Some(summary) => Some(serde_json::from_value(summary)?),
+            None => None,
+        };
+        let state = file.get_mut("game_state").map(Value::take).unwrap_or(Value::Null);
+        Ok((version as u32, summary, state))
+    }
+}
+
+/// The file name a slot is saved under. Anything but lowercase letters,
+/// digits and `-` is escaped as `_` and two hex digits per byte, so no two
+/// names share a file, even on file systems that ignore case.
+fn encode_slot_name(slot: &str) -> String {
+    slot.bytes()
+        .map(|byte| match byte {
+            b'a'..=b'z' | b'0'..=b'9' | b'-' => (byte as char).to_string(),
+            _ => format!("_{:02x}", byte),
+        })
+        .collect()
+}
+
+/// The slot name behind an encoded file name, or `None` if `file_name`
+/// wasn't produced by `encode_slot_name`.
+fn decode_slot_name(file_name: &str) -> Option<String> {
+    let mut bytes = Vec::new();
+    let mut rest = file_name;
+    while let Some(c) = rest.chars().next() {
+        if c == '_' {
+            let hex = rest.get(1..3)?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &rest[3..];
+        } else {
+            bytes.push(c as u8);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    let slot = String::from_utf8(bytes).ok()?;
+    (encode_slot_name(&slot) == file_name).then_some(slot)
+}
+
+/// The file name slots were saved under before names were encoded.
+fn legacy_file_name(slot: &str) -> String {
+    slot.trim()
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect()
+}
+
+/// Writes to a temporary file next to `path` and renames it into place, so a
+/// crash mid-write leaves the previous save untouched.
+fn write_atomic(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let temp_path = path.with_extension("json.tmp");
+    {
+        let mut file = fs::File::create(&temp_path)?;
+        file.write_all(contents)?;
+        file.sync_all()?;
+    }
+    fs::rename(&temp_path, path)
+}
+
+/// Brings a serialized `GameState` from `version` up to `SAVE_VERSION`, one
+/// step at a time.
+fn migrate(version: u32, state: &mut Value) {
+    // Old saves have no game RNG to draw from, so anything generated while
+    // migrating them comes from a fresh one
+    let mut rng = GameRng::from_entropy();
+
+    if version < 1 {
+        migrate_v0_to_v1(state, &mut rng);
+    }
+    if version < 2 {
+        migrate_v1_to_v2(state, &mut rng);
+    }
+    if version < 3 {
+        migrate_v2_to_v3(state);
+    }
+    if version < 4 {
+        migrate_v3_to_v4(state);
+    }
+    if version < 5 {
+        migrate_v4_to_v5(state);
+    }
+    if version < 6 {
+        migrate_v5_to_v6(state);
+    }
+    if version < 7 {
+        migrate_v6_to_v7(state, &mut rng);
+    }
+    if version < 8 {
+        migrate_v7_to_v8(state);
+    }
+    if version < 9 {
+        migrate_v8_to_v9(state);
+    }
+    if version < 10 {
+        migrate_v9_to_v10(state);
+    }
+    if version < 11 {
+        migrate_v10_to_v11(state);
+    }
+    if version < 12 {
+        migrate_v11_to_v12(state);
+    }
+    if version < 13 {
+        migrate_v12_to_v13(state);
+    }
+    if version < 14 {
+        migrate_v13_to_v14(state);
+    }
+}
+
+fn insert_missing(object: &mut Value, key: &str, default: impl FnOnce() -> Value) {
+    if let Some(map) = object.as_object_mut() {
+        if !map.contains_key(key) {
+            map.insert(key.to_string(), default());
+        }
+    }
+}
+
+fn for_each_in(object: &mut Value, key: &str, mut f: impl FnMut(&mut Value)) {
+    if let Some(items) = object.get_mut(key).and_then(Value::as_array_mut) {
+        items.iter_mut().for_each(&mut f);
+    }
+}
+
+/// Runs `f` over every serialized `Player`: those still playing and those
+/// kept with their career record in `retired_players`.
+fn for_each_player(state: &mut Value, mut f: impl FnMut(&mut Value)) {
+    for_each_in(state, "all_players", &mut f);
+    for_each_in(state, "retired_players", |retired| {
+        if let Some(player) = retired.get_mut("player") {
+            f(player);
+        }
+    });
+}
+
+/// Version 0 predates round-by-round simulation, series, tactics and the
+/// agent catalog.
+fn migrate_v0_to_v1(state: &mut Value, rng: &mut GameRng) {
+    insert_missing(state, "series_history", || json!([]));
+
+    for_each_in(state, "match_history", |result| {
+        insert_missing(result, "rounds", || json!([]));
+        insert_missing(result, "player_stats", || json!([]));
+    });
+
+    let mut migrate_team = |team: &mut Value| {
+        insert_missing(team, "map_proficiencies", || {
+            serde_json::to_value(Team::generate_map_proficiencies(rng)).unwrap_or(Value::Null)
+        });
+        insert_missing(team, "tactics", || {
+            serde_json::to_value(TacticalSetup::new()).unwrap_or(Value::Null)
+        });
+    };
+    if let Some(team) = state.get_mut("current_team").filter(|t| !t.is_null()) {
+        migrate_team(team);
+    }
+    for_each_in(state, "all_teams", &mut migrate_team);
+
+    for_each_in(state, "all_players", |player| {
+        let role: Option<AgentRole> = player
+            .get("preferred_role")
+            .and_then(|r| serde_json::from_value(r.clone()).ok());
+        let proficiencies: Vec<AgentProficiency> = player
+            .get("agent_proficiencies")
+            .and_then(|p| serde_json::from_value(p.clone()).ok())
+            .unwrap_or_default();
+        insert_missing(player, "agent_pool", || {
+            let pool = Player::generate_agent_pool(role.unwrap_or(AgentRole::Duelist), &proficiencies, rng);
+            serde_json::to_value(pool).unwrap_or(Value::Null)
+        });
+    });
+}
+
+/// Version 1 predates the seeded game RNG. Migrated careers get a new seed.
+fn migrate_v1_to_v2(state: &mut Value, rng: &mut GameRng) {
+    let seed: u64 = rng.gen();
+    insert_missing(state, "seed", || json!(seed));
+    insert_missing(state, "rng", || serde_json::to_value(seeded_rng(seed)).unwrap_or(Value::Null));
+}
+
+/// Version 2 predates the fixture scheduler. The current event is scheduled
+/// from scratch the next time the game moves on a day.
+fn migrate_v2_to_v3(state: &mut Value) {
+    if let Some(tournament) = state.get_mut("tournament_state") {
+        insert_missing(tournament, "event_start", || Value::Null);
+        insert_missing(tournament, "stages", || json!([]));
+        insert_missing(tournament, "fixtures", || json!([]));
+        insert_missing(tournament, "winners", || json!([]));
+    }
+}
+
+/// Version 3 kept only the winner of each international event. They become
+/// history entries without placings.
+fn migrate_v3_to_v4(state: &mut Value) {
+    let season = state.get("current_season").cloned().unwrap_or(Value::Null);
+    if let Some(tournament) = state.get_mut("tournament_state").and_then(Value::as_object_mut) {
+        let winners = tournament.remove("winners").unwrap_or_else(|| json!([]));
+        let history: Vec<Value> = winners
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|winner| {
+                json!({
+                    "season": season,
+                    "event": winner.get("event"),
+                    "champion_id": winner.get("team_id"),
+                    "placements": [],
+                })
+            })
+            .collect();
+        tournament.insert("history".to_string(), Value::Array(history));
+    }
+}
+
+/// Version 4 kept a single standings list without map or round counts. It
+/// becomes the one table the CP race is rebuilt from.
+fn migrate_v4_to_v5(state: &mut Value) {
+    let Some(tournament) = state.get_mut("tournament_state") else {
+        return;
+    };
+    for_each_in(tournament, "championship_standings", |standing| {
+        for key in ["maps_won", "maps_lost", "rounds_won", "rounds_lost"] {
+            insert_missing(standing, key, || json!(0));
+        }
+    });
+    let event = tournament.get("current_event").cloned().unwrap_or(Value::Null);
+    let standings = tournament.get("championship_standings").cloned().unwrap_or_else(|| json!([]));
+    insert_missing(tournament, "tables", || {
+        json!([{ "event": event, "region": null, "standings": standings }])
+    });
+}
+
+/// Version 5 predates training. Players start on the default schedule.
+fn migrate_v5_to_v6(state: &mut Value) {
+    for_each_in(state, "all_players", |player| {
+        insert_missing(player, "training", || {
+            serde_json::to_value(TrainingSchedule::new()).unwrap_or(Value::Null)
+        });
+        insert_missing(player, "fatigue", || json!(0));
+        insert_missing(player, "maps_this_week", || json!(0));
+    });
+}
+
+/// Version 6 kept potential as an overall rating. It becomes the ceiling of
+/// the hidden 1-200 ability pair, or is rolled from age if it's missing.
+fn migrate_v6_to_v7(state: &mut Value, rng: &mut GameRng) {
+    insert_missing(state, "scouting", || {
+        serde_json::to_value(ScoutingNetwork::new()).unwrap_or(Value::Null)
+    });
+    for_each_in(state, "all_players", |player| {
+        let age = player.get("age").and_then(Value::as_u64).unwrap_or(24) as u8;
+        let current_ability = player
+            .get("attributes")
+            .and_then(|a| serde_json::from_value::<PlayerAttributes>(a.clone()).ok())
+            .map_or(100, |a| a.ability());
+        let old_potential = player
+            .as_object_mut()
+            .and_then(|map| map.remove("potential"))
+            .and_then(|p| p.as_u64());
+        insert_missing(player, "current_ability", || json!(current_ability));
+        insert_missing(player, "potential_ability", || {
+            let potential_ability = match old_potential {
+                Some(potential) => (potential * 10).clamp(current_ability as u64, 200) as u8,
+                None => Player::generate_potential_ability(age, current_ability, rng),
+            };
+            json!(potential_ability)
+        });
+    });
+}
+
+/// Version 7 predates retirement and the news feed.
+fn migrate_v7_to_v8(state: &mut Value) {
+    insert_missing(state, "retired_players", || json!([]));
+    insert_missing(state, "news", || json!([]));
+}
+
+/// Version 8 predates burnout breaks.
+fn migrate_v8_to_v9(state: &mut Value) {
+    for_each_player(state, |player| {
+        insert_missing(player, "break_days", || json!(0));
+    });
+}
+
+/// Version 9 predates injuries.
+fn migrate_v9_to_v10(state: &mut Value) {
+    for_each_in(state, "all_players", |player| {
+        insert_missing(player, "injury", || Value::Null);
+    });
+}
+
+/// Version 10 predates contract negotiation.
+fn migrate_v10_to_v11(state: &mut Value) {
+    for_each_player(state, |player| {
+        insert_missing(player, "starter_guarantee", || json!(false));
+    });
+}
+
+/// Version 11 counted contracts in years left. They become expiry dates at
+/// the start of the season they run out in.
+fn migrate_v11_to_v12(state: &mut Value) {
+    let season = state.get("current_season").and_then(Value::as_u64).unwrap_or(2025) as u32;
+    for_each_in(state, "all_players", |player| {
+        let years = player
+            .as_object_mut()
+            .and_then(|map| map.remove("contract_length"))
+            .and_then(|length| length.as_u64())
+            .unwrap_or(0) as u32;
+        insert_missing(player, "contract_expires", || {
+            if years == 0 {
+                Value::Null
+            } else {
+                serde_json::to_value(GameState::season_start(season + years)).unwrap_or(Value::Null)
+            }
+        });
+    });
+}
+
+/// Version 12 predates the transfer market. Every club starts with the same
+/// transfer funds.
+fn migrate_v12_to_v13(state: &mut Value) {
+    insert_missing(state, "transfers", || json!([]));
+    if let Some(team) = state.get_mut("current_team") {
+        insert_missing(team, "budget", || json!(2_000_000));
+    }
+    for_each_in(state, "all_teams", |team| {
+        insert_missing(team, "budget", || json!(2_000_000));
+    });
+}
+
+/// Version 13 predates transfer listing, incoming bids and loans.
+fn migrate_v13_to_v14(state: &mut Value) {
+    insert_missing(state, "incoming_bids", || json!([]));
+    insert_missing(state, "loans", || json!([]));
+    for_each_player(state, |player| {
+        insert_missing(player, "transfer_listed", || json!(false));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::aging::RetiredPlayer;
+    use crate::game::stats::PlayerSeasonStats;
+
+    fn remove_fields(object: &mut Value, keys: &[&str]) {
+        if let Some(map) = object.as_object_mut() {
+            for key in keys {
+                map.remove(*key);
+            }
+        }
+    }
+
+    /// A career as a version 8 save would have stored it, with a retiree.
+    fn v8_fixture() -> Value {
+        let mut game = GameState::with_seed(8);
+        game.initialize_world();
+        let player = Player::new("Veteran".to_string(), &mut game.rng);
+        let career = PlayerSeasonStats::new(player.id);
+        game.retired_players.push(RetiredPlayer { player, season: 2024, career });
+
+        let mut state = serde_json::to_value(&game).unwrap();
+        remove_fields(&mut state, &["transfers", "incoming_bids", "loans"]);
+        for_each_in(&mut state, "all_teams", |team| remove_fields(team, &["budget"]));
+        for_each_player(&mut state, |player| {
+            remove_fields(
+                player,
+                &["break_days", "injury", "starter_guarantee", "contract_expires", "transfer_listed"],
+            );
+            if let Some(map) = player.as_object_mut() {
+                map.insert("contract_length".to_string(), json!(2));
+            }
+        });
+        state
+    }
+
+    #[test]
+    fn v8_save_with_retired_players_loads() {
+        let mut state = v8_fixture();
+        migrate(8, &mut state);
+        let game: GameState = serde_json::from_value(state).unwrap();
+
+        let retired = &game.retired_players[0].player;
+        assert_eq!(retired.name, "Veteran");
+        assert_eq!(retired.break_days, 0);
+        assert!(!retired.starter_guarantee);
+        assert!(!retired.transfer_listed);
+        assert!(game.all_players.iter().all(|p| p.contract_expires.is_some()));
+        assert!(game.all_teams.iter().all(|t| t.budget == 2_000_000));
+    }
+
+    #[test]
+    fn v6_potential_becomes_potential_ability() {
+        let mut state = v8_fixture();
+        remove_fields(&mut state, &["scouting", "retired_players", "news"]);
+        for_each_in(&mut state, "all_players", |player| {
+            remove_fields(player, &["current_ability", "potential_ability"]);
+            if let Some(map) = player.as_object_mut() {
+                map.insert("potential".to_string(), json!(19));
+            }
+        });
+        migrate(6, &mut state);
+        let game: GameState = serde_json::from_value(state).unwrap();
+
+        assert!(game.retired_players.is_empty());
+        for player in &game.all_players {
+            assert_eq!(player.current_ability, player.attributes.ability());
+            assert_eq!(player.potential_ability, 190.max(player.current_ability));
+        }
+    }
+
+    /// A save manager over an empty directory of its own.
+    fn temp_manager(name: &str) -> SaveManager {
+        let dir = std::env::temp_dir().join(format!("vm-save-test-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        SaveManager::new(dir)
+    }
+
+    fn career(season: u32) -> GameState {
+        let mut game = GameState::with_seed(14);
+        game.initialize_with_team("Test".to_string());
+        game.current_season = season;
+        game
+    }
+
+    fn slots(manager: &SaveManager) -> Vec<String> {
+        let mut slots: Vec<String> = manager.list_saves().into_iter().map(|s| s.slot).collect();
+        slots.sort();
+        slots
+    }
+
+    #[test]
+    fn current_save_round_trips_through_a_file() {
+        let manager = temp_manager("round-trip");
+        let game = career(2025);
+
+        manager.save("career", &game).unwrap();
+        let loaded = manager.load("career").unwrap();
+        fs::remove_dir_all(manager.dir()).unwrap();
+
+        assert_eq!(loaded.current_team.map(|t| t.name), Some("Test".to_string()));
+        assert_eq!(loaded.all_players.len(), game.all_players.len());
+    }
+
+    #[test]
+    fn similar_names_get_their_own_files() {
+        let manager = temp_manager("names");
+        let names = ["Team A", "Team_A", "team a", "Équipe #1"];
+        for (i, name) in names.iter().enumerate() {
+            manager.save(name, &career(2025 + i as u32)).unwrap();
+        }
+
+        let mut expected: Vec<String> = names.iter().map(|n| n.to_string()).collect();
+        expected.sort();
+        assert_eq!(slots(&manager), expected);
+        for (i, name) in names.iter().enumerate() {
+            assert_eq!(manager.load(name).unwrap().current_season, 2025 + i as u32);
+        }
+        fs::remove_dir_all(manager.dir()).unwrap();
+    }
+
+    #[test]
+    fn autosave_names_are_reserved() {
+        let manager = temp_manager("reserved");
+        let game = career(2025);
+        for name in ["autosave", " autosave ", "autosave-2"] {
+            assert!(matches!(manager.save(name, &game), Err(SaveError::ReservedSlotName)));
+        }
+        manager.save("autosave 2", &game).unwrap();
+        manager.save("Autosave", &game).unwrap();
+
+        for _ in 0..3 {
+            manager.autosave(&game, 2).unwrap();
+        }
+        assert_eq!(slots(&manager), ["Autosave", "autosave", "autosave 2", "autosave-1", "autosave-2"]);
+        fs::remove_dir_all(manager.dir()).unwrap();
+    }
+
+    #[test]
+    fn idle_autosaves_keep_the_backups() {
+        let manager = temp_manager("idle");
+        let mut game = career(2025);
+        manager.autosave(&game, 2).unwrap();
+        game.advance_day();
+        manager.autosave(&game, 2).unwrap();
+
+        for _ in 0..3 {
+            assert!(manager.autosave_if_changed(&game, 2).unwrap().is_none());
+        }
+        assert_eq!(slots(&manager), ["autosave", "autosave-1"]);
+        assert_eq!(manager.load("autosave-1").unwrap().current_date + chrono::Duration::days(1), game.current_date);
+
+        game.advance_day();
+        assert!(manager.autosave_if_changed(&game, 2).unwrap().is_some());
+        assert_eq!(slots(&manager), ["autosave", "autosave-1", "autosave-2"]);
+        fs::remove_dir_all(manager.dir()).unwrap();
+    }
+
+    #[test]
+    fn save_list_only_reads_the_header() {
+        let manager = temp_manager("header");
+        manager.save("career", &career(2027)).unwrap();
+
+        // Cut the career short; the summary line is all the list needs
+        let path = manager.slot_path("career").unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+        let (header, _) = contents.split_once('\n').unwrap();
+        fs::write(&path, format!("{}\n{{", header)).unwrap();
+
+        let saves = manager.list_saves();
+        assert_eq!(saves.len(), 1);
+        assert_eq!(saves[0].slot, "career");
+        assert_eq!(saves[0].season, 2027);
+        assert!(manager.load("career").is_err());
+        fs::remove_dir_all(manager.dir()).unwrap();
+    }
+
+    #[test]
+    fn single_document_saves_still_load() {
+        let manager = temp_manager("single-document");
+        let game = career(2026);
+        fs::create_dir_all(manager.dir()).unwrap();
+        let file = json!({
+            "version": SAVE_VERSION,
+            "summary": SaveSummary::from_state("Old Save", &game),
+            "game_state": game,
+        });
+        fs::write(manager.dir().join("Old_Save.json"), file.to_string()).unwrap();
+
+        assert_eq!(slots(&manager), ["Old_Save"]);
+        assert_eq!(manager.load("Old_Save").unwrap().current_season, 2026);
+
+        // Saving over it moves the slot to its encoded file name
+        manager.save("Old_Save", &game).unwrap();
+        assert!(!manager.dir().join("Old_Save.json").exists());
+        assert_eq!(slots(&manager), ["Old_Save"]);
+        fs::remove_dir_all(manager.dir()).unwrap();
+    }
+}
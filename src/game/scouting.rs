@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+use rand::Rng;
+use uuid::Uuid;
+
+use crate::game::player::Player;
+
+/// Widest potential range shown, in overall-rating points, for a player the
+/// scouts know nothing about.
+const MAX_ESTIMATE_SPREAD: u8 = 6;
+
+/// What the user's scouts have learned about one player.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoutingReport {
+    pub player_id: Uuid,
+    pub knowledge: u8, // 0-100
+}
+
+/// The user's scouting department. Better scouts learn more about a player
+/// from each trip, which narrows the estimate of their potential.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoutingNetwork {
+    pub accuracy: u8, // 1-100
+    pub reports: Vec<ScoutingReport>,
+}
+
+impl Default for ScoutingNetwork {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScoutingNetwork {
+    pub fn new() -> Self {
+        Self {
+            accuracy: 40,
+            reports: Vec::new(),
+        }
+    }
+
+    pub fn knowledge(&self, player_id: Uuid) -> u8 {
+        self.reports
+            .iter()
+            .find(|r| r.player_id == player_id)
+            .map_or(0, |r| r.knowledge)
+    }
+
+    /// Sends the scouts to watch a player. Repeat visits build on what's
+    /// already known.
+    pub fn scout<R: Rng + ?Sized>(&mut self, player_id: Uuid, rng: &mut R) {
+        let gained = self.accuracy / 2 + rng.gen_range(0..=self.accuracy / 2);
+        match self.reports.iter_mut().find(|r| r.player_id == player_id) {
+            Some(report) => report.knowledge = report.knowledge.saturating_add(gained).min(100),
+            None => self.reports.push(ScoutingReport {
+                player_id,
+                knowledge: gained.min(100),
+            }),
+        }
+    }
+
+    /// The range, on the 1-20 overall scale, the scouts put the player's
+    /// potential in. It always contains the true value and closes in on it
+    /// as knowledge grows.
+    pub fn potential_estimate(&self, player: &Player) -> (u8, u8) {
+        let potential = (player.potential_ability / 10).clamp(1, 20);
+        let unknown = 100 - self.knowledge(player.id).min(100) as u16;
+        let spread = (unknown * MAX_ESTIMATE_SPREAD as u16 / 100) as u8;
+        // Where the truth sits within the range is fixed per player, so
+        // re-scouting can't be used to average it out
+        let offset = player.id.as_bytes()[0] % (spread + 1);
+        let low = potential.saturating_sub(offset).max(1);
+        (low, (low + spread).min(20))
+    }
+}
@@ -0,0 +1,428 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::game::match_simulation::{map_defense_bias, MatchResult, MatchType, Side};
+use crate::game::rng::random_uuid;
+use crate::game::team::Team;
+
+/// The seven maps in the competitive rotation that vetoes are run over.
+pub const MAP_POOL: [&str; 7] = ["Ascent", "Bind", "Haven", "Icebox", "Fracture", "Lotus", "Split"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SeriesFormat {
+    BestOf1,
+    BestOf3,
+    BestOf5,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum VetoAction {
+    Ban,
+    Pick,
+    Decider,
+    ChooseSide,
+}
+
+impl SeriesFormat {
+    pub fn maps_to_win(&self) -> u8 {
+        match self {
+            SeriesFormat::BestOf1 => 1,
+            SeriesFormat::BestOf3 => 2,
+            SeriesFormat::BestOf5 => 3,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            SeriesFormat::BestOf1 => "Bo1",
+            SeriesFormat::BestOf3 => "Bo3",
+            SeriesFormat::BestOf5 => "Bo5",
+        }
+    }
+
+    /// The order of veto actions, as (team index, action). Team 0 starts the
+    /// veto. The team that did not pick a map chooses its starting side, and
+    /// team 0 chooses sides on the decider.
+    fn veto_sequence(&self) -> Vec<(usize, VetoAction)> {
+        use VetoAction::*;
+        match self {
+            SeriesFormat::BestOf1 => vec![
+                (0, Ban), (1, Ban), (0, Ban), (1, Ban), (0, Ban), (1, Ban),
+                (0, Decider), (1, ChooseSide),
+            ],
+            SeriesFormat::BestOf3 => vec![
+                (0, Ban), (1, Ban),
+                (0, Pick), (1, ChooseSide),
+                (1, Pick), (0, ChooseSide),
+                (0, Ban), (1, Ban),
+                (0, Decider), (0, ChooseSide),
+            ],
+            SeriesFormat::BestOf5 => vec![
+                (0, Ban), (1, Ban),
+                (0, Pick), (1, ChooseSide),
+                (1, Pick), (0, ChooseSide),
+                (0, Pick), (1, ChooseSide),
+                (1, Pick), (0, ChooseSide),
+                (0, Decider), (0, ChooseSide),
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VetoStep {
+    pub team_id: Uuid,
+    pub action: VetoAction,
+    pub map: String,
+    pub side: Option<Side>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeriesMap {
+    pub map: String,
+    pub picked_by: Option<Uuid>, // None for the decider
+    pub team1_starting_side: Side,
+    pub result: Option<MatchResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Series {
+    pub id: Uuid,
+    pub team1_id: Uuid,
+    pub team2_id: Uuid,
+    pub format: SeriesFormat,
+    pub match_type: MatchType,
+    pub veto: Vec<VetoStep>,
+    pub maps: Vec<SeriesMap>,
+    pub winner_id: Option<Uuid>,
+}
+
+impl Series {
+    pub fn new<R: Rng + ?Sized>(
+        team1_id: Uuid,
+        team2_id: Uuid,
+        format: SeriesFormat,
+        match_type: MatchType,
+        rng: &mut R,
+    ) -> Self {
+        Self {
+            id: random_uuid(rng),
+            team1_id,
+            team2_id,
+            format,
+            match_type,
+            veto: Vec::new(),
+            maps: Vec::new(),
+            winner_id: None,
+        }
+    }
+
+    fn team_id(&self, index: usize) -> Uuid {
+        if index == 0 {
+            self.team1_id
+        } else {
+            self.team2_id
+        }
+    }
+
+    /// The team that has to act next and what it has to do, or `None` once
+    /// the veto is over.
+    pub fn next_veto_action(&self) -> Option<(Uuid, VetoAction)> {
+        self.format
+            .veto_sequence()
+            .get(self.veto.len())
+            .map(|&(index, action)| (self.team_id(index), action))
+    }
+
+    pub fn is_veto_complete(&self) -> bool {
+        self.next_veto_action().is_none()
+    }
+
+    pub fn remaining_maps(&self) -> Vec<&'static str> {
+        MAP_POOL
+            .iter()
+            .copied()
+            .filter(|map| !self.veto.iter().any(|step| step.map == *map))
+            .collect()
+    }
+
+    /// Bans or picks `map` for the team whose turn it is. Returns false if it
+    /// isn't a ban or pick step or the map is no longer available.
+    pub fn veto_map(&mut self, map: &str) -> bool {
+        let Some((team_id, action)) = self.next_veto_action() else {
+            return false;
+        };
+        if !matches!(action, VetoAction::Ban | VetoAction::Pick) || !self.remaining_maps().contains(&map) {
+            return false;
+        }
+
+        self.veto.push(VetoStep {
+            team_id,
+            action,
+            map: map.to_string(),
+            side: None,
+        });
+        if action == VetoAction::Pick {
+            self.maps.push(SeriesMap {
+                map: map.to_string(),
+                picked_by: Some(team_id),
+                team1_starting_side: Side::Attack,
+                result: None,
+            });
+        }
+        self.resolve_decider();
+        true
+    }
+
+    /// Chooses the starting side on the most recently added map for the team
+    /// whose turn it is.
+    pub fn choose_side(&mut self, side: Side) -> bool {
+        let Some((team_id, VetoAction::ChooseSide)) = self.next_veto_action() else {
+            return false;
+        };
+        let Some(series_map) = self.maps.last_mut() else {
+            return false;
+        };
+
+        series_map.team1_starting_side = match (team_id == self.team1_id, side) {
+            (true, side) => side,
+            (false, Side::Attack) => Side::Defense,
+            (false, Side::Defense) => Side::Attack,
+        };
+        self.veto.push(VetoStep {
+            team_id,
+            action: VetoAction::ChooseSide,
+            map: series_map.map.clone(),
+            side: Some(side),
+        });
+        true
+    }
+
+    /// Once only one map is left it becomes the decider automatically.
+    fn resolve_decider(&mut self) {
+        if let Some((team_id, VetoAction::Decider)) = self.next_veto_action() {
+            if let Some(&map) = self.remaining_maps().first() {
+                self.veto.push(VetoStep {
+                    team_id,
+                    action: VetoAction::Decider,
+                    map: map.to_string(),
+                    side: None,
+                });
+                self.maps.push(SeriesMap {
+                    map: map.to_string(),
+                    picked_by: None,
+                    team1_starting_side: Side::Attack,
+                    result: None,
+                });
+            }
+        }
+    }
+
+    /// Takes the next veto step for an AI-controlled team: ban its weakest
+    /// map, pick its strongest, and start on defense on defender-sided maps.
+    pub fn ai_veto_step(&mut self, team: &Team) {
+        let Some((team_id, action)) = self.next_veto_action() else {
+            return;
+        };
+        if team_id != team.id {
+            return;
+        }
+
+        match action {
+            VetoAction::Ban => {
+                if let Some(map) = self.remaining_maps().into_iter().min_by_key(|m| team.get_map_proficiency(m)) {
+                    self.veto_map(map);
+                }
+            }
+            VetoAction::Pick => {
+                if let Some(map) = self.remaining_maps().into_iter().max_by_key(|m| team.get_map_proficiency(m)) {
+                    self.veto_map(map);
+                }
+            }
+            VetoAction::ChooseSide => {
+                let map = self.maps.last().map(|m| m.map.clone()).unwrap_or_default();
+                let side = if map_defense_bias(&map) >= 1.05 {
+                    Side::Defense
+                } else {
+                    Side::Attack
+                };
+                self.choose_side(side);
+            }
+            VetoAction::Decider => self.resolve_decider(),
+        }
+    }
+
+    /// Runs the whole veto with both teams AI-controlled.
+    pub fn run_ai_veto(&mut self, team1: &Team, team2: &Team) {
+        while let Some((team_id, _)) = self.next_veto_action() {
+            let before = self.veto.len();
+            if team_id == team1.id {
+                self.ai_veto_step(team1);
+            } else {
+                self.ai_veto_step(team2);
+            }
+            if self.veto.len() == before {
+                break;
+            }
+        }
+    }
+
+    pub fn maps_won(&self, team_id: Uuid) -> u8 {
+        self.maps
+            .iter()
+            .filter(|m| m.result.as_ref().and_then(|r| r.winner_id) == Some(team_id))
+            .count() as u8
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.winner_id.is_some()
+    }
+
+    /// Records the result of the next map to be played and decides the
+    /// series once a team has won enough maps.
+    pub fn record_map_result(&mut self, result: MatchResult) {
+        if self.is_complete() {
+            return;
+        }
+        if let Some(series_map) = self.maps.iter_mut().find(|m| m.result.is_none()) {
+            series_map.result = Some(result);
+        }
+
+        let maps_to_win = self.format.maps_to_win();
+        for team_id in [self.team1_id, self.team2_id] {
+            if self.maps_won(team_id) >= maps_to_win {
+                self.winner_id = Some(team_id);
+            }
+        }
+    }
+
+    pub fn next_map(&self) -> Option<&SeriesMap> {
+        if self.is_complete() {
+            return None;
+        }
+        self.maps.iter().find(|m| m.result.is_none())
+    }
+
+    pub fn map_results(&self) -> impl Iterator<Item = &MatchResult> {
+        self.maps.iter().filter_map(|m| m.result.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::rng::seeded_rng;
+
+    const FORMATS: [SeriesFormat; 3] = [SeriesFormat::BestOf1, SeriesFormat::BestOf3, SeriesFormat::BestOf5];
+
+    fn vetoed_series(format: SeriesFormat, seed: u64) -> Series {
+        let mut rng = seeded_rng(seed);
+        let team1 = Team::new("Team 1".to_string(), &mut rng);
+        let team2 = Team::new("Team 2".to_string(), &mut rng);
+        let mut series = Series::new(team1.id, team2.id, format, MatchType::Regular, &mut rng);
+        series.run_ai_veto(&team1, &team2);
+        series
+    }
+
+    fn bo3_series() -> Series {
+        let mut rng = seeded_rng(1);
+        Series::new(Uuid::from_u128(1), Uuid::from_u128(2), SeriesFormat::BestOf3, MatchType::Regular, &mut rng)
+    }
+
+    fn map_won_by(series: &Series, winner: Uuid) -> MatchResult {
+        let mut rng = seeded_rng(0);
+        let (team1_score, team2_score) = if winner == series.team1_id { (13, 7) } else { (7, 13) };
+        let map = series.next_map().map(|m| m.map.clone()).unwrap_or_default();
+        MatchResult::new(series.team1_id, series.team2_id, team1_score, team2_score, map, MatchType::Regular, &mut rng)
+    }
+
+    #[test]
+    fn ai_veto_uses_six_maps_and_leaves_the_seventh_as_decider() {
+        for format in FORMATS {
+            for seed in 0..10 {
+                let series = vetoed_series(format, seed);
+                assert!(series.is_veto_complete());
+                assert!(series.remaining_maps().is_empty());
+
+                let vetoed = series
+                    .veto
+                    .iter()
+                    .filter(|step| matches!(step.action, VetoAction::Ban | VetoAction::Pick))
+                    .count();
+                assert_eq!(vetoed, MAP_POOL.len() - 1);
+
+                assert_eq!(series.maps.len(), format.maps_to_win() as usize * 2 - 1);
+                let decider = series.maps.last().unwrap();
+                assert_eq!(decider.picked_by, None);
+                assert!(series.maps[..series.maps.len() - 1].iter().all(|m| m.picked_by.is_some()));
+
+                // Every map is played once and gets a side choice
+                for (i, series_map) in series.maps.iter().enumerate() {
+                    assert!(series.maps[i + 1..].iter().all(|m| m.map != series_map.map));
+                    assert!(series
+                        .veto
+                        .iter()
+                        .any(|step| step.action == VetoAction::ChooseSide && step.map == series_map.map));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn a_map_cannot_be_vetoed_twice() {
+        let mut series = bo3_series();
+        assert!(series.veto_map("Ascent"));
+        assert!(!series.veto_map("Ascent"));
+        assert!(!series.veto_map("Breeze"));
+        assert!(!series.choose_side(Side::Attack));
+        assert_eq!(series.veto.len(), 1);
+    }
+
+    #[test]
+    fn side_choice_is_from_the_choosing_teams_point_of_view() {
+        let mut series = bo3_series();
+        assert!(series.veto_map("Ascent"));
+        assert!(series.veto_map("Bind"));
+        assert!(series.veto_map("Haven"));
+
+        // Team 2 chooses sides on team 1's pick
+        assert_eq!(series.next_veto_action(), Some((series.team2_id, VetoAction::ChooseSide)));
+        assert!(!series.veto_map("Lotus"));
+        assert!(series.choose_side(Side::Attack));
+        assert_eq!(series.maps[0].team1_starting_side, Side::Defense);
+
+        assert!(series.veto_map("Lotus"));
+        assert!(series.choose_side(Side::Attack));
+        assert_eq!(series.maps[1].team1_starting_side, Side::Attack);
+    }
+
+    #[test]
+    fn series_stops_once_a_team_has_a_majority() {
+        for format in FORMATS {
+            let maps_to_win = format.maps_to_win();
+
+            // A clean sweep leaves the remaining maps unplayed
+            let mut series = vetoed_series(format, 3);
+            for _ in 0..maps_to_win {
+                assert!(!series.is_complete());
+                series.record_map_result(map_won_by(&series, series.team1_id));
+            }
+            assert_eq!(series.winner_id, Some(series.team1_id));
+            assert!(series.next_map().is_none());
+            series.record_map_result(map_won_by(&series, series.team2_id));
+            assert_eq!(series.map_results().count(), maps_to_win as usize);
+
+            // Trading maps goes the distance
+            let mut series = vetoed_series(format, 3);
+            for map in 0..series.maps.len() {
+                assert!(!series.is_complete());
+                let winner = if map % 2 == 0 { series.team2_id } else { series.team1_id };
+                series.record_map_result(map_won_by(&series, winner));
+            }
+            assert_eq!(series.winner_id, Some(series.team2_id));
+            assert_eq!(series.maps_won(series.team2_id), maps_to_win);
+            assert_eq!(series.maps_won(series.team1_id), maps_to_win - 1);
+        }
+    }
+}
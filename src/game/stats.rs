@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::game::match_simulation::MatchResult;
+
+/// A player's numbers added up over a set of maps, e.g. a season.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerSeasonStats {
+    pub player_id: Uuid,
+    pub maps_played: u32,
+    pub rounds_played: u32,
+    pub kills: u32,
+    pub deaths: u32,
+    pub assists: u32,
+    pub damage: u32,
+    pub first_kills: u32,
+    pub clutches_won: u32,
+}
+
+impl PlayerSeasonStats {
+    pub fn new(player_id: Uuid) -> Self {
+        Self {
+            player_id,
+            maps_played: 0,
+            rounds_played: 0,
+            kills: 0,
+            deaths: 0,
+            assists: 0,
+            damage: 0,
+            first_kills: 0,
+            clutches_won: 0,
+        }
+    }
+
+    pub fn kdr(&self) -> f32 {
+        if self.deaths == 0 {
+            self.kills as f32
+        } else {
+            self.kills as f32 / self.deaths as f32
+        }
+    }
+
+    /// Average damage per round.
+    pub fn adr(&self) -> f32 {
+        self.damage as f32 / self.rounds_played.max(1) as f32
+    }
+
+    /// Average combat score, using the same weights as a single map.
+    pub fn acs(&self) -> f32 {
+        (self.kills as f32 * 150.0 + self.assists as f32 * 50.0 + self.damage as f32 * 0.15)
+            / self.rounds_played.max(1) as f32
+    }
+}
+
+/// Adds up every player's stats across `results`.
+pub fn aggregate_player_stats<'a>(results: impl IntoIterator<Item = &'a MatchResult>) -> Vec<PlayerSeasonStats> {
+    let mut totals: Vec<PlayerSeasonStats> = Vec::new();
+
+    for result in results {
+        let rounds = (result.team1_score + result.team2_score) as u32;
+        for stats in &result.player_stats {
+            let index = match totals.iter().position(|t| t.player_id == stats.player_id) {
+                Some(index) => index,
+                None => {
+                    totals.push(PlayerSeasonStats::new(stats.player_id));
+                    totals.len() - 1
+                }
+            };
+            let total = &mut totals[index];
+            total.maps_played += 1;
+            total.rounds_played += rounds;
+            total.kills += stats.kills as u32;
+            total.deaths += stats.deaths as u32;
+            total.assists += stats.assists as u32;
+            total.damage += stats.damage;
+            total.first_kills += stats.first_kills as u32;
+            total.clutches_won += stats.clutches_won as u32;
+        }
+    }
+
+    totals
+}
@@ -0,0 +1,208 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game::agent::Agent;
+use crate::game::player::Player;
+use crate::game::series::MAP_POOL;
+use crate::game::team::TeamComposition;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum AttackStyle {
+    DefaultSpread,
+    FastExecute,
+    SlowDefault,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum DefenseStyle {
+    Standard,
+    AggressiveAngles,
+    StackSites,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum EconomicPolicy {
+    Conservative,
+    AggressiveForceBuys,
+    SaveForFullBuys,
+}
+
+impl AttackStyle {
+    pub fn name(&self) -> &str {
+        match self {
+            AttackStyle::DefaultSpread => "Default spread",
+            AttackStyle::FastExecute => "Fast execute",
+            AttackStyle::SlowDefault => "Slow default",
+        }
+    }
+
+    /// Change to the attackers' chance of getting the spike down before the
+    /// next fight.
+    pub fn plant_modifier(&self) -> f64 {
+        match self {
+            AttackStyle::DefaultSpread => 0.0,
+            AttackStyle::FastExecute => 0.15,
+            AttackStyle::SlowDefault => -0.05,
+        }
+    }
+}
+
+impl DefenseStyle {
+    pub fn name(&self) -> &str {
+        match self {
+            DefenseStyle::Standard => "Standard setup",
+            DefenseStyle::AggressiveAngles => "Aggressive angles",
+            DefenseStyle::StackSites => "Stack sites",
+        }
+    }
+}
+
+impl EconomicPolicy {
+    pub fn name(&self) -> &str {
+        match self {
+            EconomicPolicy::Conservative => "Conservative economy",
+            EconomicPolicy::AggressiveForceBuys => "Aggressive force buys",
+            EconomicPolicy::SaveForFullBuys => "Save for full buys",
+        }
+    }
+}
+
+/// Multiplier applied to the attackers' duels for a given attack style
+/// against a given defense style. Fast executes crash into stacked sites,
+/// slow defaults punish defenders who push for picks.
+pub fn tactical_matchup(attack: AttackStyle, defense: DefenseStyle) -> f32 {
+    match (attack, defense) {
+        (AttackStyle::DefaultSpread, DefenseStyle::Standard) => 1.0,
+        (AttackStyle::DefaultSpread, DefenseStyle::AggressiveAngles) => 0.96,
+        (AttackStyle::DefaultSpread, DefenseStyle::StackSites) => 1.03,
+        (AttackStyle::FastExecute, DefenseStyle::Standard) => 1.04,
+        (AttackStyle::FastExecute, DefenseStyle::AggressiveAngles) => 1.05,
+        (AttackStyle::FastExecute, DefenseStyle::StackSites) => 0.92,
+        (AttackStyle::SlowDefault, DefenseStyle::Standard) => 0.98,
+        (AttackStyle::SlowDefault, DefenseStyle::AggressiveAngles) => 1.06,
+        (AttackStyle::SlowDefault, DefenseStyle::StackSites) => 1.04,
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MapTactics {
+    pub map: String,
+    pub attack_style: AttackStyle,
+    pub defense_style: DefenseStyle,
+    pub economic_policy: EconomicPolicy,
+    pub composition: Vec<Agent>, // One agent per starter
+}
+
+impl MapTactics {
+    pub fn new(map: String) -> Self {
+        let composition = Self::recommended_composition(&map).to_vec();
+
+        Self {
+            map,
+            attack_style: AttackStyle::DefaultSpread,
+            defense_style: DefenseStyle::Standard,
+            economic_policy: EconomicPolicy::Conservative,
+            composition,
+        }
+    }
+
+    pub fn recommended_composition(map: &str) -> [Agent; 5] {
+        match map {
+            "Ascent" => [Agent::Jett, Agent::Sova, Agent::Omen, Agent::Killjoy, Agent::KayO],
+            "Bind" => [Agent::Raze, Agent::Skye, Agent::Viper, Agent::Brimstone, Agent::Cypher],
+            "Breeze" | "Icebox" => [Agent::Jett, Agent::Sova, Agent::Viper, Agent::Killjoy, Agent::KayO],
+            "Haven" | "Lotus" => [Agent::Raze, Agent::Fade, Agent::Omen, Agent::Killjoy, Agent::Breach],
+            "Split" => [Agent::Raze, Agent::Skye, Agent::Viper, Agent::Cypher, Agent::Omen],
+            _ => [Agent::Neon, Agent::Breach, Agent::Brimstone, Agent::Chamber, Agent::Fade],
+        }
+    }
+
+    pub fn role_composition(&self) -> TeamComposition {
+        TeamComposition::from_agents(&self.composition)
+    }
+
+    /// Five different agents - the same agent can't be picked twice.
+    pub fn is_valid_composition(&self) -> bool {
+        self.composition.len() == 5
+            && self
+                .composition
+                .iter()
+                .enumerate()
+                .all(|(i, agent)| !self.composition[..i].contains(agent))
+    }
+}
+
+/// Hands the agents in a composition out to the lineup so that the total
+/// agent proficiency is as high as possible.
+pub fn assign_agents<'a>(players: &[&'a Player], composition: &[Agent]) -> Vec<(&'a Player, Agent)> {
+    fn search(
+        players: &[&Player],
+        composition: &[Agent],
+        used: &mut Vec<bool>,
+        current: &mut Vec<usize>,
+        best: &mut (u32, Vec<usize>),
+    ) {
+        if current.len() == players.len().min(composition.len()) {
+            let total = current
+                .iter()
+                .enumerate()
+                .map(|(p, &a)| players[p].get_agent_proficiency(composition[a]) as u32)
+                .sum();
+            if total > best.0 || best.1.is_empty() {
+                *best = (total, current.clone());
+            }
+            return;
+        }
+        for a in 0..composition.len() {
+            if !used[a] {
+                used[a] = true;
+                current.push(a);
+                search(players, composition, used, current, best);
+                current.pop();
+                used[a] = false;
+            }
+        }
+    }
+
+    let mut best = (0, Vec::new());
+    search(players, composition, &mut vec![false; composition.len()], &mut Vec::new(), &mut best);
+    best.1
+        .into_iter()
+        .enumerate()
+        .map(|(p, a)| (players[p], composition[a]))
+        .collect()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TacticalSetup {
+    pub maps: Vec<MapTactics>,
+}
+
+impl Default for TacticalSetup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TacticalSetup {
+    pub fn new() -> Self {
+        Self {
+            maps: MAP_POOL.iter().map(|map| MapTactics::new(map.to_string())).collect(),
+        }
+    }
+
+    pub fn get_map_tactics(&self, map: &str) -> MapTactics {
+        self.maps
+            .iter()
+            .find(|t| t.map == map)
+            .cloned()
+            .unwrap_or_else(|| MapTactics::new(map.to_string()))
+    }
+
+    pub fn set_map_tactics(&mut self, tactics: MapTactics) {
+        if let Some(existing) = self.maps.iter_mut().find(|t| t.map == tactics.map) {
+            *existing = tactics;
+        } else {
+            self.maps.push(tactics);
+        }
+    }
+}
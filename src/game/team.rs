@@ -1,6 +1,17 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
+use rand::Rng;
+use crate::game::agent::Agent;
 use crate::game::player::AgentRole;
+use crate::game::rng::random_uuid;
+use crate::game::series::MAP_POOL;
+use crate::game::tactics::TacticalSetup;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MapProficiency {
+    pub map: String,
+    pub proficiency: u8, // 1-20 scale
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Team {
@@ -15,12 +26,15 @@ pub struct Team {
     pub wins: u32,
     pub losses: u32,
     pub team_cohesion: u8, // 1-20 scale
+    pub map_proficiencies: Vec<MapProficiency>,
+    pub tactics: TacticalSetup,
+    pub budget: i64, // Transfer funds for AI clubs; the user's club uses GameState::budget
 }
 
 impl Team {
-    pub fn new(name: String) -> Self {
+    pub fn new<R: Rng + ?Sized>(name: String, rng: &mut R) -> Self {
         Self {
-            id: Uuid::new_v4(),
+            id: random_uuid(rng),
             name: name.clone(),
             nickname: name, // Use name as nickname for backward compatibility
             players: Vec::new(),
@@ -31,12 +45,15 @@ impl Team {
             wins: 0,
             losses: 0,
             team_cohesion: 10, // Start with average cohesion
+            map_proficiencies: Self::generate_map_proficiencies(rng),
+            tactics: TacticalSetup::new(),
+            budget: rng.gen_range(10..=40) * 100_000,
         }
     }
 
-    pub fn new_with_details(name: String, nickname: String, region: String) -> Self {
+    pub fn new_with_details<R: Rng + ?Sized>(name: String, nickname: String, region: String, rng: &mut R) -> Self {
         Self {
-            id: Uuid::new_v4(),
+            id: random_uuid(rng),
             name,
             nickname,
             players: Vec::new(),
@@ -47,9 +64,30 @@ impl Team {
             wins: 0,
             losses: 0,
             team_cohesion: 10, // Start with average cohesion
+            map_proficiencies: Self::generate_map_proficiencies(rng),
+            tactics: TacticalSetup::new(),
+            budget: rng.gen_range(10..=40) * 100_000,
         }
     }
 
+    pub(crate) fn generate_map_proficiencies<R: Rng + ?Sized>(rng: &mut R) -> Vec<MapProficiency> {
+        MAP_POOL
+            .iter()
+            .map(|map| MapProficiency {
+                map: map.to_string(),
+                proficiency: rng.gen_range(6..=16),
+            })
+            .collect()
+    }
+
+    pub fn get_map_proficiency(&self, map: &str) -> u8 {
+        self.map_proficiencies
+            .iter()
+            .find(|p| p.map == map)
+            .map(|p| p.proficiency)
+            .unwrap_or(5)
+    }
+
     pub fn add_player(&mut self, player_id: Uuid) {
         if !self.players.contains(&player_id) {
             self.players.push(player_id);
@@ -64,6 +102,13 @@ impl Team {
     pub fn remove_player(&mut self, player_id: Uuid) {
         self.players.retain(|&id| id != player_id);
         self.starting_lineup.retain(|&id| id != player_id);
+
+        // Fill the gap in the lineup from the bench
+        if let Some(&bench_id) = self.players.iter().find(|id| !self.starting_lineup.contains(id)) {
+            if self.starting_lineup.len() < 5 {
+                self.starting_lineup.push(bench_id);
+            }
+        }
     }
 
     pub fn set_starting_lineup(&mut self, lineup: Vec<Uuid>) {
@@ -115,6 +160,12 @@ pub struct TeamComposition {
     pub sentinel_count: u8,
 }
 
+impl Default for TeamComposition {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TeamComposition {
     pub fn new() -> Self {
         Self {
@@ -125,6 +176,14 @@ impl TeamComposition {
         }
     }
 
+    pub fn from_agents(agents: &[Agent]) -> Self {
+        let mut composition = Self::new();
+        for agent in agents {
+            composition.add_role(agent.role());
+        }
+        composition
+    }
+
     pub fn add_role(&mut self, role: AgentRole) {
         match role {
             AgentRole::Duelist => self.duelist_count += 1,
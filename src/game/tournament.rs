@@ -1,8 +1,14 @@
 use serde::{Deserialize, Serialize};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, Duration, Utc};
 use uuid::Uuid;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use crate::game::bracket::{BracketResult, Stage, StageFormat};
+use crate::game::match_simulation::MatchType;
+use crate::game::rng::random_uuid;
+use crate::game::series::{Series, SeriesFormat};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum VCTEvent {
     Kickoff,
     MastersBangkok,
@@ -12,13 +18,116 @@ pub enum VCTEvent {
     ChampionsParis,
 }
 
+impl VCTEvent {
+    pub fn name(&self) -> &'static str {
+        match self {
+            VCTEvent::Kickoff => "VCT Kickoff",
+            VCTEvent::MastersBangkok => "Masters Bangkok",
+            VCTEvent::Stage1 => "VCT Stage 1",
+            VCTEvent::MastersToronto => "Masters Toronto",
+            VCTEvent::Stage2 => "VCT Stage 2",
+            VCTEvent::ChampionsParis => "Champions Paris",
+        }
+    }
+
+    pub fn is_international(&self) -> bool {
+        matches!(self, VCTEvent::MastersBangkok | VCTEvent::MastersToronto | VCTEvent::ChampionsParis)
+    }
+
+    pub fn next(&self) -> Option<VCTEvent> {
+        match self {
+            VCTEvent::Kickoff => Some(VCTEvent::MastersBangkok),
+            VCTEvent::MastersBangkok => Some(VCTEvent::Stage1),
+            VCTEvent::Stage1 => Some(VCTEvent::MastersToronto),
+            VCTEvent::MastersToronto => Some(VCTEvent::Stage2),
+            VCTEvent::Stage2 => Some(VCTEvent::ChampionsParis),
+            VCTEvent::ChampionsParis => None,
+        }
+    }
+
+    /// Earliest start of the event, in days after the season starts.
+    fn start_day(&self) -> i64 {
+        match self {
+            VCTEvent::Kickoff => 14,         // Mid January
+            VCTEvent::MastersBangkok => 50,  // Late February
+            VCTEvent::Stage1 => 72,          // Mid March
+            VCTEvent::MastersToronto => 157, // Early June
+            VCTEvent::Stage2 => 195,         // Mid July
+            VCTEvent::ChampionsParis => 254, // Mid September
+        }
+    }
+
+    /// How many teams from each region go on to the next international event.
+    fn qualifiers_per_region(&self) -> usize {
+        match self {
+            VCTEvent::Stage2 => 4,
+            _ => 2,
+        }
+    }
+
+    /// Qualifying spots per region decided by the event's playoff placings.
+    /// The rest go to the league's championship points leaders.
+    fn qualifiers_by_result(&self) -> usize {
+        match self {
+            VCTEvent::Stage2 => 2,
+            _ => self.qualifiers_per_region(),
+        }
+    }
+
+    /// Championship points for each final placing at an international event,
+    /// best first.
+    fn placement_points(&self) -> &'static [u32] {
+        match self {
+            VCTEvent::MastersBangkok | VCTEvent::MastersToronto => &[5, 4, 3, 2, 1, 1],
+            _ => &[],
+        }
+    }
+
+    /// Prize money for each final placing at an international event, best
+    /// first.
+    fn prize_money(&self) -> &'static [i64] {
+        match self {
+            VCTEvent::MastersBangkok | VCTEvent::MastersToronto => {
+                &[350_000, 200_000, 125_000, 80_000, 50_000, 50_000, 30_000, 30_000]
+            }
+            VCTEvent::ChampionsParis => &[
+                1_000_000, 500_000, 300_000, 200_000, 125_000, 125_000, 75_000, 75_000, 40_000, 40_000, 40_000,
+                40_000, 25_000, 25_000, 25_000, 25_000,
+            ],
+            _ => &[],
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TournamentState {
     pub current_event: VCTEvent,
     pub current_week: u8,
     pub events_completed: Vec<VCTEvent>,
-    pub qualified_teams: Vec<Uuid>,
-    pub championship_standings: Vec<ChampionshipStanding>,
+    pub qualified_teams: Vec<Uuid>, // Qualified for the next international event
+    pub championship_standings: Vec<ChampionshipStanding>, // The season's CP race, built from `tables`
+    pub tables: Vec<StandingsTable>,
+    pub event_start: Option<DateTime<Utc>>, // None until the event is scheduled
+    pub stages: Vec<Stage>, // Groups and brackets of the current event
+    pub fixtures: Vec<Match>, // Every fixture of the season so far
+    pub history: Vec<EventResult>, // Every international event played, oldest first
+}
+
+/// How an international event finished.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventResult {
+    pub season: u32,
+    pub event: VCTEvent,
+    pub champion_id: Uuid,
+    pub placements: Vec<EventPlacement>, // Best first
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventPlacement {
+    pub team_id: Uuid,
+    pub place: usize,
+    pub points: u32,
+    pub prize_money: i64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -27,6 +136,126 @@ pub struct ChampionshipStanding {
     pub points: u32,
     pub wins: u32,
     pub losses: u32,
+    pub maps_won: u32,
+    pub maps_lost: u32,
+    pub rounds_won: u32,
+    pub rounds_lost: u32,
+}
+
+impl ChampionshipStanding {
+    pub fn new(team_id: Uuid) -> Self {
+        Self {
+            team_id,
+            points: 0,
+            wins: 0,
+            losses: 0,
+            maps_won: 0,
+            maps_lost: 0,
+            rounds_won: 0,
+            rounds_lost: 0,
+        }
+    }
+
+    pub fn map_differential(&self) -> i32 {
+        self.maps_won as i32 - self.maps_lost as i32
+    }
+
+    pub fn round_differential(&self) -> i32 {
+        self.rounds_won as i32 - self.rounds_lost as i32
+    }
+
+    fn record_series(&mut self, series: &Series) {
+        if series.winner_id == Some(self.team_id) {
+            self.wins += 1;
+        } else {
+            self.losses += 1;
+        }
+        for map in series.map_results() {
+            let (won, lost) = if map.team1_id == self.team_id {
+                (map.team1_score, map.team2_score)
+            } else {
+                (map.team2_score, map.team1_score)
+            };
+            if map.winner_id == Some(self.team_id) {
+                self.maps_won += 1;
+            } else {
+                self.maps_lost += 1;
+            }
+            self.rounds_won += won as u32;
+            self.rounds_lost += lost as u32;
+        }
+    }
+
+    fn add(&mut self, other: &ChampionshipStanding) {
+        self.points += other.points;
+        self.wins += other.wins;
+        self.losses += other.losses;
+        self.maps_won += other.maps_won;
+        self.maps_lost += other.maps_lost;
+        self.rounds_won += other.rounds_won;
+        self.rounds_lost += other.rounds_lost;
+    }
+}
+
+/// Standings of one event: one table per league at regional events, a
+/// single table at international ones.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StandingsTable {
+    pub event: VCTEvent,
+    pub region: Option<String>,
+    pub standings: Vec<ChampionshipStanding>,
+}
+
+impl StandingsTable {
+    fn new(event: VCTEvent, region: Option<String>, teams: &[Uuid]) -> Self {
+        Self {
+            event,
+            region,
+            standings: teams.iter().map(|&id| ChampionshipStanding::new(id)).collect(),
+        }
+    }
+
+    pub fn get_team_standing(&self, team_id: Uuid) -> Option<&ChampionshipStanding> {
+        self.standings.iter().find(|s| s.team_id == team_id)
+    }
+}
+
+/// Orders standings by points then series wins. Teams still level are
+/// split by their head-to-head wins against each other, then map
+/// difference, then round difference.
+fn rank_standings<'a>(standings: &mut [ChampionshipStanding], results: impl Iterator<Item = &'a Series> + Clone) {
+    standings.sort_by_key(|s| std::cmp::Reverse((s.points, s.wins)));
+
+    let mut start = 0;
+    while start < standings.len() {
+        let level = (standings[start].points, standings[start].wins);
+        let end = standings[start..]
+            .iter()
+            .position(|s| (s.points, s.wins) != level)
+            .map_or(standings.len(), |len| start + len);
+
+        let tied: Vec<Uuid> = standings[start..end].iter().map(|s| s.team_id).collect();
+        let head_to_head = |team_id: Uuid| {
+            results
+                .clone()
+                .filter(|series| {
+                    series.winner_id == Some(team_id)
+                        && tied.contains(&series.team1_id)
+                        && tied.contains(&series.team2_id)
+                })
+                .count()
+        };
+        standings[start..end].sort_by_cached_key(|s| {
+            std::cmp::Reverse((head_to_head(s.team_id), s.map_differential(), s.round_differential()))
+        });
+        start = end;
+    }
+}
+
+impl Default for TournamentState {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl TournamentState {
@@ -37,75 +266,533 @@ impl TournamentState {
             events_completed: Vec::new(),
             qualified_teams: Vec::new(),
             championship_standings: Vec::new(),
+            tables: Vec::new(),
+            event_start: None,
+            stages: Vec::new(),
+            fixtures: Vec::new(),
+            history: Vec::new(),
         }
     }
 
-    pub fn advance_week(&mut self) {
-        self.current_week += 1;
-        
-        // Simple progression logic - advance to next event after certain weeks
-        match (&self.current_event, self.current_week) {
-            (VCTEvent::Kickoff, 4) => {
-                self.complete_event(VCTEvent::Kickoff);
-                self.current_event = VCTEvent::MastersBangkok;
-                self.current_week = 1;
+    pub fn is_season_complete(&self) -> bool {
+        self.events_completed.contains(&VCTEvent::ChampionsParis)
+    }
+
+    /// Brings the schedule up to `date`: starts the current event once it's
+    /// due, adds the next stage when one finishes, moves on to the next event
+    /// once every stage is done, and gives a date to every match whose teams
+    /// are now known. `teams_by_region` is every team in each league, in no
+    /// particular order.
+    pub fn update_schedule<R: Rng + ?Sized>(
+        &mut self,
+        date: DateTime<Utc>,
+        season_start: DateTime<Utc>,
+        teams_by_region: &[(String, Vec<Uuid>)],
+        rng: &mut R,
+    ) {
+        self.seed_standings(teams_by_region);
+
+        loop {
+            if self.is_season_complete() {
+                return;
             }
-            (VCTEvent::MastersBangkok, 2) => {
-                self.complete_event(VCTEvent::MastersBangkok);
-                self.current_event = VCTEvent::Stage1;
-                self.current_week = 1;
+
+            let Some(event_start) = self.event_start else {
+                let start = self.next_event_start(season_start);
+                if date < start {
+                    return;
+                }
+                self.start_event(start, teams_by_region, rng);
+                continue;
+            };
+            self.current_week = ((date - event_start).num_days().max(0) / 7 + 1) as u8;
+
+            for stage in &mut self.stages {
+                stage.pair_next_round();
             }
-            (VCTEvent::Stage1, 8) => {
-                self.complete_event(VCTEvent::Stage1);
-                self.current_event = VCTEvent::MastersToronto;
-                self.current_week = 1;
+            if !self.stages.iter().all(Stage::is_complete) {
+                break;
             }
-            (VCTEvent::MastersToronto, 2) => {
-                self.complete_event(VCTEvent::MastersToronto);
-                self.current_event = VCTEvent::Stage2;
-                self.current_week = 1;
+            if !self.add_next_stages(date) {
+                self.finish_event(season_start.year() as u32);
             }
-            (VCTEvent::Stage2, 8) => {
-                self.complete_event(VCTEvent::Stage2);
-                self.current_event = VCTEvent::ChampionsParis;
-                self.current_week = 1;
+        }
+
+        self.schedule_ready_matches(date, rng);
+    }
+
+    /// The event can't start before its usual date, nor before the last
+    /// event's final has been played.
+    fn next_event_start(&self, season_start: DateTime<Utc>) -> DateTime<Utc> {
+        let nominal = season_start + Duration::days(self.current_event.start_day());
+        let after_last = self
+            .fixtures
+            .iter()
+            .map(|f| f.scheduled_date + Duration::days(3))
+            .max();
+        after_last.map_or(nominal, |after| nominal.max(after))
+    }
+
+    fn start_event<R: Rng + ?Sized>(
+        &mut self,
+        start: DateTime<Utc>,
+        teams_by_region: &[(String, Vec<Uuid>)],
+        rng: &mut R,
+    ) {
+        self.event_start = Some(start);
+        self.current_week = 1;
+        self.stages.clear();
+
+        let event = self.current_event;
+        if event.is_international() {
+            self.tables.push(StandingsTable::new(event, None, &self.qualified_teams));
+        } else {
+            for (region, teams) in teams_by_region {
+                self.tables.push(StandingsTable::new(event, Some(region.clone()), teams));
+            }
+        }
+
+        if event == VCTEvent::ChampionsParis {
+            // Qualifiers come in by place then region, so each group gets one
+            // team from every seed pot and every league
+            let teams = &self.qualified_teams;
+            let group_count = teams.len() / 4;
+            for group in 0..group_count {
+                let seeded = (0..4)
+                    .filter_map(|pot| teams.get(pot * group_count + (group + pot) % group_count).copied())
+                    .collect();
+                let name = format!("Group {}", (b'A' + group as u8) as char);
+                self.stages
+                    .push(Stage::gsl(name, None, seeded, SeriesFormat::BestOf3).starting(start, 2));
+            }
+            return;
+        }
+
+        // Masters opens with a Swiss stage for every qualified team
+        if event.is_international() {
+            let teams = self.qualified_teams.clone();
+            self.stages.push(
+                Stage::swiss("Swiss Stage".to_string(), None, teams, SeriesFormat::BestOf3).starting(start, 2),
+            );
+            return;
+        }
+
+        // Kickoff is a single triple-elimination bracket per league
+        if event == VCTEvent::Kickoff {
+            for (region, teams) in teams_by_region {
+                let seeded = self.seed_regional_teams(teams, rng);
+                self.stages.push(
+                    Stage::triple_elimination(
+                        format!("{} Kickoff", region),
+                        Some(region.clone()),
+                        seeded,
+                        SeriesFormat::BestOf3,
+                        SeriesFormat::BestOf5,
+                    )
+                    .starting(start, 2),
+                );
+            }
+            return;
+        }
+
+        // Other regional events open with two round-robin groups per league
+        for (region, teams) in teams_by_region {
+            let seeded = self.seed_regional_teams(teams, rng);
+            let (group_a, group_b): (Vec<_>, Vec<_>) = seeded
+                .iter()
+                .enumerate()
+                .partition(|(i, _)| i % 4 == 0 || i % 4 == 3);
+            for (name, group) in [("Group A", group_a), ("Group B", group_b)] {
+                let group = group.into_iter().map(|(_, &id)| id).collect();
+                self.stages.push(
+                    Stage::round_robin(format!("{} {}", region, name), Some(region.clone()), group, SeriesFormat::BestOf3)
+                        .starting(start, 7),
+                );
             }
-            _ => {}
         }
     }
 
-    fn complete_event(&mut self, event: VCTEvent) {
-        self.events_completed.push(event);
+    /// Seeds a league by championship points, with ties broken at random.
+    fn seed_regional_teams<R: Rng + ?Sized>(&self, teams: &[Uuid], rng: &mut R) -> Vec<Uuid> {
+        let mut seeded = teams.to_vec();
+        seeded.shuffle(rng);
+        seeded.sort_by_key(|&id| std::cmp::Reverse(self.get_team_standing(id).map(|s| s.points).unwrap_or(0)));
+        seeded
     }
 
-    pub fn add_championship_points(&mut self, team_id: Uuid, points: u32) {
-        if let Some(standing) = self.championship_standings.iter_mut().find(|s| s.team_id == team_id) {
-            standing.points += points;
+    /// Once the regional groups are done, the top two of each group meet in
+    /// a playoff bracket, and teams through a Swiss stage or GSL groups go on
+    /// to a double-elimination playoff. Returns false when the event has no
+    /// stages left.
+    fn add_next_stages(&mut self, date: DateTime<Utc>) -> bool {
+        if self.current_event.is_international() {
+            return self.add_international_playoffs(date);
+        }
+
+        let regional_groups: Vec<&Stage> = self
+            .stages
+            .iter()
+            .filter(|s| s.format == StageFormat::RoundRobin && s.region.is_some())
+            .collect();
+        if regional_groups.is_empty() || self.stages.iter().any(|s| s.format != StageFormat::RoundRobin) {
+            return false;
+        }
+
+        let mut regions: Vec<String> = Vec::new();
+        for stage in &regional_groups {
+            if let Some(region) = &stage.region {
+                if !regions.contains(region) {
+                    regions.push(region.clone());
+                }
+            }
+        }
+
+        let mut playoffs = Vec::new();
+        for region in regions {
+            let groups: Vec<Vec<Uuid>> = regional_groups
+                .iter()
+                .filter(|s| s.region.as_ref() == Some(&region))
+                .map(|s| s.placements())
+                .collect();
+            // Group winners face the other group's runner-up
+            let mut teams = Vec::new();
+            for place in 0..2 {
+                teams.extend(groups.iter().filter_map(|g| g.get(place).copied()));
+            }
+            if teams.len() == 4 {
+                teams.swap(2, 3);
+            }
+            playoffs.push(
+                Stage::single_elimination(
+                    format!("{} Playoffs", region),
+                    Some(region.clone()),
+                    teams,
+                    SeriesFormat::BestOf3,
+                    SeriesFormat::BestOf5,
+                )
+                .starting(date + Duration::days(3), 3),
+            );
+        }
+        self.stages.extend(playoffs);
+        true
+    }
+
+    fn add_international_playoffs(&mut self, date: DateTime<Utc>) -> bool {
+        let group_stages = self
+            .stages
+            .iter()
+            .all(|s| matches!(s.format, StageFormat::Swiss | StageFormat::Gsl));
+        if self.stages.is_empty() || !group_stages {
+            return false;
+        }
+
+        let teams = if let [swiss] = self.stages.as_slice() {
+            swiss.swiss_advancing()
         } else {
-            self.championship_standings.push(ChampionshipStanding {
+            // Group winners face a runner-up from the other half of the draw
+            let groups: Vec<Vec<Uuid>> = self.stages.iter().map(Stage::placements).collect();
+            let half = groups.len() / 2;
+            let winners = groups.iter().filter_map(|g| g.first().copied());
+            let runners_up = (0..groups.len()).filter_map(|i| groups[(i + half) % groups.len()].get(1).copied());
+            winners.chain(runners_up).collect()
+        };
+        self.stages.push(
+            Stage::double_elimination("Playoffs".to_string(), None, teams, SeriesFormat::BestOf3, SeriesFormat::BestOf5)
+                .starting(date + Duration::days(3), 2),
+        );
+        true
+    }
+
+    fn finish_event(&mut self, season: u32) {
+        let event = self.current_event;
+
+        if event.is_international() {
+            self.award_placements(season);
+            self.qualified_teams.clear();
+        } else {
+            // Interleave the leagues so regions are kept apart early on
+            let per_region = event.qualifiers_per_region();
+            let by_region: Vec<Vec<Uuid>> = self
+                .stages
+                .iter()
+                .filter(|s| s.format != StageFormat::RoundRobin)
+                .map(|playoffs| {
+                    let mut teams: Vec<Uuid> = playoffs.placements().into_iter().take(event.qualifiers_by_result()).collect();
+                    // Any spots left go to the league's best teams on
+                    // championship points
+                    let mut league: Vec<Uuid> = self
+                        .stages
+                        .iter()
+                        .filter(|s| s.format == StageFormat::RoundRobin && s.region == playoffs.region)
+                        .flat_map(|s| s.placements())
+                        .collect();
+                    league.sort_by_key(|&id| std::cmp::Reverse(self.get_team_standing(id).map_or(0, |s| s.points)));
+                    for team_id in league {
+                        if teams.len() >= per_region {
+                            break;
+                        }
+                        if !teams.contains(&team_id) {
+                            teams.push(team_id);
+                        }
+                    }
+                    teams
+                })
+                .collect();
+            self.qualified_teams = (0..per_region)
+                .flat_map(|place| by_region.iter().filter_map(move |teams| teams.get(place).copied()))
+                .collect();
+        }
+
+        self.events_completed.push(event);
+        if let Some(next) = event.next() {
+            self.current_event = next;
+            self.event_start = None;
+            self.current_week = 1;
+        }
+    }
+
+    /// Records the event in the history and hands out championship points
+    /// by final placing.
+    fn award_placements(&mut self, season: u32) {
+        // The last stage decides the top places. Teams knocked out in the
+        // groups follow by where they finished in their group
+        let Some((last, groups)) = self.stages.split_last() else {
+            return;
+        };
+        let mut teams = last.placements();
+        let groups: Vec<Vec<Uuid>> = groups.iter().map(Stage::placements).collect();
+        let most = groups.iter().map(Vec::len).max().unwrap_or(0);
+        for place in 0..most {
+            for team_id in groups.iter().filter_map(|g| g.get(place).copied()) {
+                if !teams.contains(&team_id) {
+                    teams.push(team_id);
+                }
+            }
+        }
+        let Some(&champion_id) = teams.first() else {
+            return;
+        };
+
+        let event = self.current_event;
+        let placements: Vec<EventPlacement> = teams
+            .into_iter()
+            .enumerate()
+            .map(|(i, team_id)| EventPlacement {
                 team_id,
-                points,
-                wins: 0,
-                losses: 0,
-            });
+                place: i + 1,
+                points: event.placement_points().get(i).copied().unwrap_or(0),
+                prize_money: event.prize_money().get(i).copied().unwrap_or(0),
+            })
+            .collect();
+        for placement in &placements {
+            if placement.points > 0 {
+                self.add_championship_points(placement.team_id, placement.points);
+            }
+        }
+        self.history.push(EventResult {
+            season,
+            event,
+            champion_id,
+            placements,
+        });
+    }
+
+    fn schedule_ready_matches<R: Rng + ?Sized>(&mut self, date: DateTime<Utc>, rng: &mut R) {
+        for stage_index in 0..self.stages.len() {
+            for match_index in self.stages[stage_index].ready_matches() {
+                let stage = &self.stages[stage_index];
+                let Some((team1_id, team2_id)) = stage.match_teams(match_index) else {
+                    continue;
+                };
+                let bracket_match = &stage.matches[match_index];
+                let scheduled_date = stage.round_date(bracket_match.round).max(date);
+                let fixture = Match {
+                    id: random_uuid(rng),
+                    team1_id,
+                    team2_id,
+                    scheduled_date,
+                    format: bracket_match.format,
+                    event: self.current_event,
+                    match_type: Self::match_type(self.current_event, stage.format),
+                    stage_index,
+                    match_index,
+                    label: format!("{} - {}", stage.name, bracket_match.label),
+                    completed: false,
+                    result: None,
+                };
+                self.stages[stage_index].matches[match_index].fixture_id = Some(fixture.id);
+                self.fixtures.push(fixture);
+            }
+        }
+        self.fixtures.sort_by_key(|f| f.scheduled_date);
+    }
+
+    fn match_type(event: VCTEvent, format: StageFormat) -> MatchType {
+        match (event, format) {
+            (VCTEvent::Kickoff, _) => MatchType::Kickoff,
+            (VCTEvent::MastersBangkok | VCTEvent::MastersToronto, _) => MatchType::Masters,
+            (VCTEvent::ChampionsParis, _) => MatchType::Champions,
+            (_, StageFormat::RoundRobin) => MatchType::Regular,
+            (_, _) => MatchType::Playoff,
         }
-        
-        // Sort standings by points
-        self.championship_standings.sort_by(|a, b| b.points.cmp(&a.points));
     }
 
-    pub fn record_match_result(&mut self, team_id: Uuid, won: bool) {
-        if let Some(standing) = self.championship_standings.iter_mut().find(|s| s.team_id == team_id) {
-            if won {
-                standing.wins += 1;
-                // Award 1 championship point for regular season wins
-                if matches!(self.current_event, VCTEvent::Stage1 | VCTEvent::Stage2) {
-                    standing.points += 1;
+    pub fn event_result(&self, season: u32, event: VCTEvent) -> Option<&EventResult> {
+        self.history.iter().find(|r| r.season == season && r.event == event)
+    }
+
+    pub fn event_winner(&self, season: u32, event: VCTEvent) -> Option<Uuid> {
+        self.event_result(season, event).map(|r| r.champion_id)
+    }
+
+    pub fn get_fixture(&self, fixture_id: Uuid) -> Option<&Match> {
+        self.fixtures.iter().find(|f| f.id == fixture_id)
+    }
+
+    /// Fixtures on or before `date` that haven't been played.
+    pub fn due_fixtures(&self, date: DateTime<Utc>) -> impl Iterator<Item = &Match> {
+        self.fixtures
+            .iter()
+            .filter(move |f| !f.completed && f.scheduled_date <= date)
+    }
+
+    pub fn team_fixtures(&self, team_id: Uuid) -> impl Iterator<Item = &Match> {
+        self.fixtures.iter().filter(move |f| f.involves(team_id))
+    }
+
+    /// Records a played fixture in its stage and the championship standings.
+    pub fn record_fixture_result(&mut self, fixture_id: Uuid, series: Series) {
+        let Some(fixture) = self.fixtures.iter_mut().find(|f| f.id == fixture_id && !f.completed) else {
+            return;
+        };
+        let Some(winner_id) = series.winner_id else {
+            return;
+        };
+        let loser_id = if winner_id == fixture.team1_id {
+            fixture.team2_id
+        } else {
+            fixture.team1_id
+        };
+
+        let result = BracketResult {
+            winner_id,
+            loser_id,
+            winner_maps: series.maps_won(winner_id),
+            loser_maps: series.maps_won(loser_id),
+        };
+        let (stage_index, match_index) = (fixture.stage_index, fixture.match_index);
+        fixture.completed = true;
+        fixture.result = Some(series.clone());
+
+        if let Some(stage) = self.stages.get_mut(stage_index) {
+            stage.record_result(match_index, result);
+        }
+
+        let event = self.current_event;
+        for team_id in [winner_id, loser_id] {
+            let standing = self.table_standing_mut(event, team_id);
+            standing.record_series(&series);
+            // A point for every win in the Stage 1 and Stage 2 leagues
+            if team_id == winner_id && matches!(event, VCTEvent::Stage1 | VCTEvent::Stage2) {
+                standing.points += 1;
+            }
+        }
+        self.refresh_standings();
+    }
+
+    /// Awards points to a team in the current event's table.
+    pub fn add_championship_points(&mut self, team_id: Uuid, points: u32) {
+        self.table_standing_mut(self.current_event, team_id).points += points;
+        self.refresh_standings();
+    }
+
+    /// The team's row in the event's table, added to a table of its own if
+    /// it isn't in one yet.
+    fn table_standing_mut(&mut self, event: VCTEvent, team_id: Uuid) -> &mut ChampionshipStanding {
+        let index = match self
+            .tables
+            .iter()
+            .position(|t| t.event == event && t.get_team_standing(team_id).is_some())
+        {
+            Some(index) => index,
+            None => {
+                let index = self
+                    .tables
+                    .iter()
+                    .position(|t| t.event == event && t.region.is_none())
+                    .unwrap_or_else(|| {
+                        self.tables.push(StandingsTable::new(event, None, &[]));
+                        self.tables.len() - 1
+                    });
+                self.tables[index].standings.push(ChampionshipStanding::new(team_id));
+                index
+            }
+        };
+        let table = &mut self.tables[index];
+        let row = table.standings.iter().position(|s| s.team_id == team_id).unwrap_or(0);
+        &mut table.standings[row]
+    }
+
+    /// Makes sure every team has a row in the CP race, even before it plays.
+    fn seed_standings(&mut self, teams_by_region: &[(String, Vec<Uuid>)]) {
+        let missing: Vec<Uuid> = teams_by_region
+            .iter()
+            .flat_map(|(_, teams)| teams.iter().copied())
+            .filter(|&id| self.get_team_standing(id).is_none())
+            .collect();
+        if !missing.is_empty() {
+            self.championship_standings
+                .extend(missing.into_iter().map(ChampionshipStanding::new));
+            self.refresh_standings();
+        }
+    }
+
+    /// Ranks every event table and rebuilds the CP race from them.
+    fn refresh_standings(&mut self) {
+        for table in &mut self.tables {
+            let event = table.event;
+            let results = self
+                .fixtures
+                .iter()
+                .filter(move |f| f.event == event)
+                .filter_map(|f| f.result.as_ref());
+            rank_standings(&mut table.standings, results);
+        }
+
+        let mut race: Vec<ChampionshipStanding> = self
+            .championship_standings
+            .iter()
+            .map(|s| ChampionshipStanding::new(s.team_id))
+            .collect();
+        for row in self.tables.iter().flat_map(|t| &t.standings) {
+            match race.iter_mut().find(|s| s.team_id == row.team_id) {
+                Some(standing) => standing.add(row),
+                None => {
+                    let mut standing = ChampionshipStanding::new(row.team_id);
+                    standing.add(row);
+                    race.push(standing);
                 }
-            } else {
-                standing.losses += 1;
             }
         }
+        rank_standings(&mut race, self.fixtures.iter().filter_map(|f| f.result.as_ref()));
+        self.championship_standings = race;
+    }
+
+    /// Tables for one event, a league's or the single international one.
+    pub fn event_tables(&self, event: VCTEvent) -> impl Iterator<Item = &StandingsTable> {
+        self.tables.iter().filter(move |t| t.event == event)
+    }
+
+    pub fn event_table(&self, event: VCTEvent, region: Option<&str>) -> Option<&StandingsTable> {
+        self.event_tables(event).find(|t| t.region.as_deref() == region)
+    }
+
+    /// Next season's calendar. Only the event history carries over.
+    pub fn next_season(&self) -> Self {
+        Self {
+            history: self.history.clone(),
+            ..Self::new()
+        }
     }
 
     pub fn get_team_standing(&self, team_id: Uuid) -> Option<&ChampionshipStanding> {
@@ -113,70 +800,267 @@ impl TournamentState {
     }
 
     pub fn is_qualified_for_masters(&self, team_id: Uuid) -> bool {
-        // Top 2 from Kickoff qualify for Masters Bangkok
-        // Top 3 from Stage 1 qualify for Masters Toronto
-        match self.current_event {
-            VCTEvent::Kickoff => {
-                self.championship_standings.iter().take(2).any(|s| s.team_id == team_id)
-            }
-            VCTEvent::Stage1 => {
-                self.championship_standings.iter().take(3).any(|s| s.team_id == team_id)
-            }
-            _ => false,
-        }
+        // The top two of each league's Kickoff bracket and Stage 1 playoffs
+        matches!(
+            self.current_event,
+            VCTEvent::Kickoff | VCTEvent::MastersBangkok | VCTEvent::Stage1 | VCTEvent::MastersToronto
+        ) && self.qualified_teams.contains(&team_id)
     }
 
     pub fn is_qualified_for_champions(&self, team_id: Uuid) -> bool {
-        // Top 2 from Stage 2 + Top 2 CP leaders qualify for Champions
-        if let Some(_standing) = self.get_team_standing(team_id) {
-            // Simplified: top 4 teams by championship points qualify
-            self.championship_standings.iter().take(4).any(|s| s.team_id == team_id)
-        } else {
-            false
-        }
+        // The top two of each league's Stage 2 playoffs and the next two on
+        // championship points
+        matches!(self.current_event, VCTEvent::Stage2 | VCTEvent::ChampionsParis)
+            && self.qualified_teams.contains(&team_id)
     }
 
     pub fn get_current_event_name(&self) -> &str {
-        match self.current_event {
-            VCTEvent::Kickoff => "VCT Kickoff",
-            VCTEvent::MastersBangkok => "Masters Bangkok",
-            VCTEvent::Stage1 => "VCT Stage 1",
-            VCTEvent::MastersToronto => "Masters Toronto",
-            VCTEvent::Stage2 => "VCT Stage 2",
-            VCTEvent::ChampionsParis => "Champions Paris",
-        }
+        self.current_event.name()
     }
 }
 
+/// A scheduled series between two teams in one of the season's events.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Match {
     pub id: Uuid,
     pub team1_id: Uuid,
     pub team2_id: Uuid,
     pub scheduled_date: DateTime<Utc>,
-    pub map: String,
+    pub format: SeriesFormat,
     pub event: VCTEvent,
+    pub match_type: MatchType,
+    pub stage_index: usize,
+    pub match_index: usize, // Position in the stage's bracket
+    pub label: String,
     pub completed: bool,
-    pub result: Option<crate::game::MatchResult>,
+    pub result: Option<Series>,
 }
 
 impl Match {
-    pub fn new(
-        team1_id: Uuid,
-        team2_id: Uuid,
-        scheduled_date: DateTime<Utc>,
-        map: String,
-        event: VCTEvent,
-    ) -> Self {
-        Self {
-            id: Uuid::new_v4(),
-            team1_id,
-            team2_id,
-            scheduled_date,
-            map,
-            event,
-            completed: false,
-            result: None,
+    pub fn involves(&self, team_id: Uuid) -> bool {
+        self.team1_id == team_id || self.team2_id == team_id
+    }
+
+    pub fn opponent(&self, team_id: Uuid) -> Uuid {
+        if self.team1_id == team_id {
+            self.team2_id
+        } else {
+            self.team1_id
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::match_simulation::{MatchResult, Side};
+    use crate::game::rng::seeded_rng;
+    use crate::game::series::{SeriesMap, MAP_POOL};
+    use chrono::TimeZone;
+
+    const REGIONS: [&str; 4] = ["Americas", "EMEA", "Pacific", "China"];
+
+    fn season_start() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap()
+    }
+
+    /// Twelve teams a league. Lower ids are the better teams.
+    fn leagues() -> Vec<(String, Vec<Uuid>)> {
+        REGIONS
+            .iter()
+            .enumerate()
+            .map(|(r, region)| {
+                let teams = (0..12).map(|i| Uuid::from_u128((i * REGIONS.len() + r + 1) as u128)).collect();
+                (region.to_string(), teams)
+            })
+            .collect()
+    }
+
+    /// A played series with one map per score, scores from `team1`'s side.
+    fn series(team1: Uuid, team2: Uuid, scores: &[(u8, u8)]) -> Series {
+        let mut rng = seeded_rng(0);
+        let mut series = Series::new(team1, team2, SeriesFormat::BestOf3, MatchType::Regular, &mut rng);
+        for (map, &(team1_score, team2_score)) in MAP_POOL.iter().zip(scores) {
+            series.maps.push(SeriesMap {
+                map: map.to_string(),
+                picked_by: None,
+                team1_starting_side: Side::Attack,
+                result: None,
+            });
+            let result =
+                MatchResult::new(team1, team2, team1_score, team2_score, map.to_string(), MatchType::Regular, &mut rng);
+            series.record_map_result(result);
+        }
+        series
+    }
+
+    /// Plays every fixture due on each day, the lower id winning 2-0, until
+    /// `done` says to stop.
+    fn play_until(state: &mut TournamentState, date: &mut DateTime<Utc>, done: impl Fn(&TournamentState) -> bool) {
+        let leagues = leagues();
+        let mut rng = seeded_rng(1);
+        for _ in 0..400 {
+            state.update_schedule(*date, season_start(), &leagues, &mut rng);
+            if done(state) {
+                return;
+            }
+            let due: Vec<Match> = state.due_fixtures(*date).cloned().collect();
+            for fixture in due {
+                let score = if fixture.team1_id < fixture.team2_id { (13, 5) } else { (5, 13) };
+                state.record_fixture_result(fixture.id, series(fixture.team1_id, fixture.team2_id, &[score, score]));
+            }
+            *date += Duration::days(1);
+        }
+        panic!("the season never got there");
+    }
+
+    fn standing(id: u128, points: u32, maps: (u32, u32), rounds: (u32, u32)) -> ChampionshipStanding {
+        ChampionshipStanding {
+            points,
+            wins: 1,
+            losses: 1,
+            maps_won: maps.0,
+            maps_lost: maps.1,
+            rounds_won: rounds.0,
+            rounds_lost: rounds.1,
+            ..ChampionshipStanding::new(Uuid::from_u128(id))
         }
     }
-} 
\ No newline at end of file
+
+    fn ranked(mut standings: Vec<ChampionshipStanding>, results: &[Series]) -> Vec<u128> {
+        rank_standings(&mut standings, results.iter());
+        standings.iter().map(|s| s.team_id.as_u128()).collect()
+    }
+
+    #[test]
+    fn level_teams_are_split_by_head_to_head_then_maps_then_rounds() {
+        // Points come before everything else
+        let standings = vec![standing(1, 0, (4, 0), (52, 10)), standing(2, 1, (0, 4), (10, 52))];
+        assert_eq!(ranked(standings, &[]), [2, 1]);
+
+        // Head-to-head beats a better map difference
+        let (team1, team2) = (Uuid::from_u128(1), Uuid::from_u128(2));
+        let head_to_head = series(team2, team1, &[(13, 11), (13, 11)]);
+        let standings = vec![standing(1, 1, (3, 1), (40, 30)), standing(2, 1, (2, 2), (30, 40))];
+        assert_eq!(ranked(standings, &[head_to_head]), [2, 1]);
+
+        // Then map difference beats a better round difference
+        let standings = vec![standing(1, 1, (2, 2), (60, 20)), standing(2, 1, (3, 2), (40, 50))];
+        assert_eq!(ranked(standings, &[]), [2, 1]);
+
+        // Then round difference
+        let standings = vec![standing(1, 1, (2, 2), (40, 45)), standing(2, 1, (2, 2), (45, 40))];
+        assert_eq!(ranked(standings, &[]), [2, 1]);
+
+        // A win against a team that isn't level doesn't count
+        let elsewhere = series(Uuid::from_u128(3), team2, &[(5, 13), (5, 13)]);
+        let standings = vec![standing(1, 1, (3, 2), (40, 40)), standing(2, 1, (2, 2), (40, 40))];
+        assert_eq!(ranked(standings, &[elsewhere]), [1, 2]);
+    }
+
+    #[test]
+    fn every_event_and_league_keeps_its_own_table() {
+        let mut state = TournamentState::new();
+        let mut date = season_start();
+        play_until(&mut state, &mut date, |s| s.current_event == VCTEvent::MastersToronto);
+
+        let leagues = leagues();
+        for event in [VCTEvent::Kickoff, VCTEvent::Stage1] {
+            assert_eq!(state.event_tables(event).count(), leagues.len());
+            for (region, teams) in &leagues {
+                let table = state.event_table(event, Some(region)).unwrap();
+                let mut ids: Vec<Uuid> = table.standings.iter().map(|s| s.team_id).collect();
+                ids.sort();
+                assert_eq!(&ids, teams);
+            }
+        }
+        let masters = state.event_table(VCTEvent::MastersBangkok, None).unwrap();
+        assert_eq!(state.event_tables(VCTEvent::MastersBangkok).count(), 1);
+        assert_eq!(masters.standings.len(), 8);
+
+        // Each table only counts its own event's series, and the CP race
+        // adds them all up
+        for (_, teams) in &leagues {
+            for &team_id in teams {
+                let mut total = (0, 0);
+                for table in &state.tables {
+                    let Some(row) = table.get_team_standing(team_id) else {
+                        continue;
+                    };
+                    let played: Vec<&Match> = state
+                        .team_fixtures(team_id)
+                        .filter(|f| f.event == table.event && f.completed)
+                        .collect();
+                    let won = played.iter().filter(|f| f.result.as_ref().unwrap().winner_id == Some(team_id)).count();
+                    assert_eq!((row.wins as usize, row.losses as usize), (won, played.len() - won));
+                    if table.event == VCTEvent::Stage1 {
+                        assert!(row.points >= row.wins);
+                    }
+                    if table.event == VCTEvent::Kickoff {
+                        assert_eq!(row.points, 0);
+                    }
+                    total = (total.0 + row.wins, total.1 + row.points);
+                }
+                let race = state.get_team_standing(team_id).unwrap();
+                assert_eq!((race.wins, race.points), total);
+            }
+        }
+    }
+
+    #[test]
+    fn champions_plays_gsl_groups_into_the_playoffs() {
+        let mut state = TournamentState::new();
+        let mut date = season_start();
+        play_until(&mut state, &mut date, |s| s.stages.iter().any(|stage| stage.format == StageFormat::Gsl));
+
+        // Four groups, each with one team from every league
+        let leagues = leagues();
+        assert_eq!(state.stages.len(), 4);
+        for group in &state.stages {
+            assert_eq!(group.format, StageFormat::Gsl);
+            assert_eq!(group.teams.len(), 4);
+            for (_, teams) in &leagues {
+                assert_eq!(group.teams.iter().filter(|id| teams.contains(id)).count(), 1);
+            }
+        }
+
+        play_until(&mut state, &mut date, |s| s.stages.len() == 5);
+        let through: Vec<Uuid> = state.stages[..4].iter().flat_map(|g| g.placements()[..2].to_vec()).collect();
+        let mut playoffs = state.stages[4].teams.clone();
+        playoffs.sort();
+        let mut expected = through.clone();
+        expected.sort();
+        assert_eq!(playoffs, expected);
+
+        play_until(&mut state, &mut date, TournamentState::is_season_complete);
+        let result = state.event_result(2025, VCTEvent::ChampionsParis).unwrap();
+        assert_eq!(result.placements.len(), 16);
+        assert_eq!(result.champion_id, result.placements[0].team_id);
+        assert_eq!(result.placements[0].prize_money, 1_000_000);
+        // Everyone knocked out in the groups places below the playoff teams
+        for placement in &result.placements[8..] {
+            assert!(!through.contains(&placement.team_id));
+        }
+    }
+
+    #[test]
+    fn next_season_keeps_the_history_and_starts_afresh() {
+        let mut state = TournamentState::new();
+        let mut date = season_start();
+        play_until(&mut state, &mut date, TournamentState::is_season_complete);
+        assert_eq!(state.history.len(), 3);
+
+        let next = state.next_season();
+        assert_eq!(next.history.len(), 3);
+        let champion = state.event_winner(2025, VCTEvent::ChampionsParis);
+        assert_eq!(next.event_winner(2025, VCTEvent::ChampionsParis), champion);
+        assert_eq!(next.current_event, VCTEvent::Kickoff);
+        assert!(next.events_completed.is_empty());
+        assert!(next.tables.is_empty());
+        assert!(next.championship_standings.is_empty());
+        assert!(next.fixtures.is_empty());
+        assert!(next.stages.is_empty());
+        assert!(next.qualified_teams.is_empty());
+        assert!(next.event_start.is_none());
+    }
+}
@@ -0,0 +1,249 @@
+use serde::{Deserialize, Serialize};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::game::condition::add_fatigue;
+use crate::game::player::{Player, PlayerAttributes};
+
+/// Chance of a focused attribute going up in a week, before age, potential,
+/// intensity, experience, happiness and fatigue are taken into account.
+const BASE_WEEKLY_GROWTH: f32 = 0.2;
+
+/// An attribute training can raise, with the name it's shown under.
+type TrainableAttribute = (&'static str, fn(&mut PlayerAttributes) -> &mut u8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TrainingFocus {
+    Aim,
+    Utility,
+    Role,
+    AgentPool,
+}
+
+impl TrainingFocus {
+    pub const ALL: [TrainingFocus; 4] = [
+        TrainingFocus::Aim,
+        TrainingFocus::Utility,
+        TrainingFocus::Role,
+        TrainingFocus::AgentPool,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TrainingFocus::Aim => "Aim",
+            TrainingFocus::Utility => "Utility",
+            TrainingFocus::Role => "Role",
+            TrainingFocus::AgentPool => "Agent Pool",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TrainingIntensity {
+    Light,
+    Normal,
+    Intense,
+}
+
+impl TrainingIntensity {
+    pub const ALL: [TrainingIntensity; 3] = [
+        TrainingIntensity::Light,
+        TrainingIntensity::Normal,
+        TrainingIntensity::Intense,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TrainingIntensity::Light => "Light",
+            TrainingIntensity::Normal => "Normal",
+            TrainingIntensity::Intense => "Intense",
+        }
+    }
+
+    fn growth_multiplier(&self) -> f32 {
+        match self {
+            TrainingIntensity::Light => 0.6,
+            TrainingIntensity::Normal => 1.0,
+            TrainingIntensity::Intense => 1.5,
+        }
+    }
+
+    /// Fatigue added by a week of training.
+    fn fatigue(&self) -> u8 {
+        match self {
+            TrainingIntensity::Light => 2,
+            TrainingIntensity::Normal => 6,
+            TrainingIntensity::Intense => 14,
+        }
+    }
+
+    fn happiness_change(&self) -> i8 {
+        match self {
+            TrainingIntensity::Light => 1,
+            TrainingIntensity::Normal => 0,
+            TrainingIntensity::Intense => -1,
+        }
+    }
+}
+
+/// What a player works on each week.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TrainingSchedule {
+    pub focus: TrainingFocus,
+    pub intensity: TrainingIntensity,
+}
+
+impl Default for TrainingSchedule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TrainingSchedule {
+    pub fn new() -> Self {
+        Self {
+            focus: TrainingFocus::Aim,
+            intensity: TrainingIntensity::Normal,
+        }
+    }
+}
+
+/// Runs one week of a player's training schedule. Young players a long way
+/// short of their potential grow fastest, and maps played during the week
+/// speed things up. Returns the names of the attributes that went up.
+pub fn train_player<R: Rng + ?Sized>(player: &mut Player, rng: &mut R) -> Vec<&'static str> {
+    let schedule = player.training;
+    let age_factor = match player.age {
+        0..=20 => 1.0,
+        21..=23 => 0.75,
+        24..=26 => 0.45,
+        27..=29 => 0.2,
+        _ => 0.05,
+    };
+    let headroom = player.potential_ability as f32 - player.current_ability as f32;
+    // Nothing left to grow into once a player reaches their potential
+    let potential_factor = if headroom > 0.0 { (headroom / 40.0).clamp(0.05, 1.0) } else { 0.0 };
+    let experience = 1.0 + player.maps_this_week.min(6) as f32 * 0.1;
+    let happiness = 1.0 + player.training_happiness as f32 / 40.0;
+    let freshness = 1.0 - player.fatigue as f32 / 200.0;
+    let chance = BASE_WEEKLY_GROWTH
+        * age_factor
+        * potential_factor
+        * schedule.intensity.growth_multiplier()
+        * experience
+        * happiness
+        * freshness;
+
+    let mut improved = Vec::new();
+    if rng.gen_bool(chance.clamp(0.0, 1.0) as f64) {
+        improved.extend(improve_focus(player, schedule.focus, rng));
+    }
+    // Match experience also rounds out the mental side of the game
+    if player.maps_this_week > 0 && rng.gen_bool((chance * 0.3).clamp(0.0, 1.0) as f64) {
+        let options: [TrainableAttribute; 3] = [
+            ("Game Sense", |a| &mut a.game_sense),
+            ("Composure", |a| &mut a.composure),
+            ("Adaptability", |a| &mut a.adaptability),
+        ];
+        if let Some((name, attribute)) = options.choose(rng) {
+            if raise(attribute(&mut player.attributes)) {
+                improved.push(*name);
+            }
+        }
+    }
+
+    let intensity = schedule.intensity;
+    add_fatigue(player, intensity.fatigue());
+    player.training_happiness = (player.training_happiness + intensity.happiness_change()).clamp(-10, 10);
+    player.maps_this_week = 0;
+    if !improved.is_empty() {
+        player.update_ratings();
+    }
+    improved
+}
+
+fn improve_focus<R: Rng + ?Sized>(player: &mut Player, focus: TrainingFocus, rng: &mut R) -> Option<&'static str> {
+    let options: &[TrainableAttribute] = match focus {
+        TrainingFocus::Aim => &[
+            ("Aim", |a| &mut a.aim),
+            ("Movement", |a| &mut a.movement),
+            ("Clutch", |a| &mut a.clutch_potential),
+        ],
+        TrainingFocus::Utility => &[
+            ("Utility", |a| &mut a.utility_usage),
+            ("Game Sense", |a| &mut a.game_sense),
+            ("Communication", |a| &mut a.communication),
+        ],
+        TrainingFocus::Role => {
+            let role = player.preferred_role;
+            if let Some(proficiency) = player.agent_proficiencies.iter_mut().find(|p| p.role == role) {
+                if raise(&mut proficiency.proficiency) {
+                    return Some("Role Proficiency");
+                }
+            }
+            &[("Adaptability", |a| &mut a.adaptability)]
+        }
+        TrainingFocus::AgentPool => {
+            // Work on the weakest agent in the pool
+            let weakest = player.agent_pool.iter_mut().min_by_key(|m| m.proficiency)?;
+            return raise(&mut weakest.proficiency).then_some("Agent Pool");
+        }
+    };
+
+    let (name, attribute) = options.choose(rng)?;
+    raise(attribute(&mut player.attributes)).then_some(*name)
+}
+
+/// Adds a point to a 1-20 rating. Returns false if it was already maxed.
+fn raise(value: &mut u8) -> bool {
+    if *value < 20 {
+        *value += 1;
+        true
+    } else {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::rng::seeded_rng;
+
+    /// Trains a teenager on an intense schedule with a full week of matches,
+    /// resting them between weeks. Returns how many attributes went up.
+    fn train_for_a_year(player: &mut Player, rng: &mut impl Rng) -> usize {
+        player.age = 17;
+        player.training.intensity = TrainingIntensity::Intense;
+        (0..52)
+            .map(|_| {
+                player.fatigue = 0;
+                player.maps_this_week = 6;
+                train_player(player, rng).len()
+            })
+            .sum()
+    }
+
+    #[test]
+    fn players_stop_growing_at_their_potential() {
+        let mut rng = seeded_rng(3);
+        for _ in 0..20 {
+            let mut player = Player::new("Capped".to_string(), &mut rng);
+            player.potential_ability = player.current_ability;
+            let ability = player.current_ability;
+            assert_eq!(train_for_a_year(&mut player, &mut rng), 0);
+            assert_eq!(player.current_ability, ability);
+
+            // Or already past it
+            player.potential_ability = ability.saturating_sub(10);
+            assert_eq!(train_for_a_year(&mut player, &mut rng), 0);
+        }
+    }
+
+    #[test]
+    fn players_with_headroom_grow() {
+        let mut rng = seeded_rng(3);
+        let mut player = Player::new("Prospect".to_string(), &mut rng);
+        player.potential_ability = 200;
+        assert!(train_for_a_year(&mut player, &mut rng) > 0);
+    }
+}
@@ -0,0 +1,206 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::game::contract::round_to_thousand;
+use crate::game::player::Player;
+use crate::game::team::Team;
+
+/// Bids at least this share of the asking price get a counter-offer rather
+/// than a flat rejection.
+const COUNTER_THRESHOLD: f64 = 0.75;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TransferKind {
+    Transfer,
+    FreeSigning,
+    Loan,
+    Release,
+}
+
+impl TransferKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TransferKind::Transfer => "Transfer",
+            TransferKind::FreeSigning => "Free",
+            TransferKind::Loan => "Loan",
+            TransferKind::Release => "Released",
+        }
+    }
+}
+
+/// A completed move, kept for the transfer history.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferRecord {
+    pub date: DateTime<Utc>,
+    pub player_id: Uuid,
+    pub player_name: String,
+    pub from_team: Option<Uuid>, // None for free agents
+    pub to_team: Option<Uuid>, // None for releases
+    pub fee: i64,
+    pub kind: TransferKind,
+}
+
+/// A fee agreed with a player's club, pending personal terms.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferBid {
+    pub player_id: Uuid,
+    pub from_team: Uuid,
+    pub fee: i64,
+}
+
+/// An AI club's offer for one of the user's transfer-listed players.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncomingBid {
+    pub id: Uuid,
+    pub player_id: Uuid,
+    pub team_id: Uuid,
+    pub fee: i64,
+    pub expires: DateTime<Utc>,
+}
+
+/// A player out on loan until the end of the season.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Loan {
+    pub player_id: Uuid,
+    pub from_team: Uuid,
+    pub to_team: Uuid,
+    pub return_date: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransferResponse {
+    Accepted,
+    Countered(i64),
+    Rejected,
+}
+
+/// Why a bid couldn't be put to a club.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BidError {
+    NoTeam,
+    InvalidFee,
+    CantAfford,
+    OnLoan,
+    NotAtAClub, // A free agent, or a player who has left the game
+}
+
+/// Players the dressing room looks up to: starters who are either the
+/// team's best player or one of its leading voices.
+pub fn is_popular(player: &Player, team: &Team, players: &[Player]) -> bool {
+    let best = best_player(team, players);
+    team.starting_lineup.contains(&player.id)
+        && (best == Some(player.id) || player.attributes.communication >= 14)
+}
+
+/// What it costs to tear up a contract: the salary still owed to its end.
+pub fn termination_payout(player: &Player, date: DateTime<Utc>) -> i64 {
+    let Some(expires) = player.contract_expires else {
+        return 0;
+    };
+    let days_left = (expires - date).num_days().max(0);
+    round_to_thousand(player.contract_salary as f64 * days_left as f64 / 365.0)
+}
+
+/// What a club wants for one of its players. Stars and starters cost more
+/// than squad players, so does a long contract, and clubs short of money
+/// sell for less.
+pub fn asking_price(player: &Player, team: &Team, players: &[Player], date: DateTime<Utc>) -> i64 {
+    let best = best_player(team, players);
+    let importance = if best == Some(player.id) {
+        2.0
+    } else if team.starting_lineup.contains(&player.id) {
+        1.5
+    } else {
+        1.0
+    };
+    let contract = 1.0 + player.contract_years_left(date) as f64 * 0.15;
+    let finances = match team.budget {
+        budget if budget < 1_000_000 => 0.85,
+        budget if budget > 3_000_000 => 1.1,
+        _ => 1.0,
+    };
+
+    round_to_thousand(player.market_value as f64 * importance * contract * finances)
+}
+
+/// A club's answer to a bid for one of its players.
+pub fn evaluate_bid(asking_price: i64, fee: i64) -> TransferResponse {
+    if fee >= asking_price {
+        TransferResponse::Accepted
+    } else if fee as f64 >= asking_price as f64 * COUNTER_THRESHOLD {
+        TransferResponse::Countered(asking_price)
+    } else {
+        TransferResponse::Rejected
+    }
+}
+
+fn best_player(team: &Team, players: &[Player]) -> Option<Uuid> {
+    team.players
+        .iter()
+        .filter_map(|id| players.iter().find(|p| p.id == *id))
+        .max_by_key(|p| p.current_ability)
+        .map(|p| p.id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::rng::seeded_rng;
+    use chrono::TimeZone;
+
+    fn date() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap()
+    }
+
+    /// A club of six players on equal terms, the first five starting.
+    fn club() -> (Team, Vec<Player>) {
+        let mut rng = seeded_rng(5);
+        let mut team = Team::new("Sellers".to_string(), &mut rng);
+        team.budget = 2_000_000;
+        let players: Vec<Player> = (0..6)
+            .map(|i| {
+                let mut player = Player::new(format!("Player {}", i), &mut rng);
+                player.market_value = 500_000;
+                player.contract_expires = Some(Utc.with_ymd_and_hms(2027, 1, 1, 0, 0, 0).unwrap());
+                team.add_player(player.id);
+                player
+            })
+            .collect();
+        (team, players)
+    }
+
+    #[test]
+    fn bids_are_accepted_countered_or_rejected_against_the_asking_price() {
+        assert_eq!(evaluate_bid(1_000_000, 1_200_000), TransferResponse::Accepted);
+        assert_eq!(evaluate_bid(1_000_000, 1_000_000), TransferResponse::Accepted);
+        assert_eq!(evaluate_bid(1_000_000, 750_000), TransferResponse::Countered(1_000_000));
+        assert_eq!(evaluate_bid(1_000_000, 749_000), TransferResponse::Rejected);
+    }
+
+    #[test]
+    fn stars_starters_and_long_contracts_cost_more() {
+        let (mut team, mut players) = club();
+        let bench = *team.players.iter().find(|id| !team.starting_lineup.contains(id)).unwrap();
+        players.iter_mut().find(|p| p.id == bench).unwrap().current_ability = 1;
+        let best = best_player(&team, &players).unwrap();
+        let starter = *team.starting_lineup.iter().find(|&&id| id != best).unwrap();
+        let price = |id: Uuid, team: &Team, players: &[Player]| {
+            asking_price(players.iter().find(|p| p.id == id).unwrap(), team, players, date())
+        };
+
+        // Two years left: 1.3 times value, then 2x for the star and 1.5x for a starter
+        assert_eq!(price(bench, &team, &players), 650_000);
+        assert_eq!(price(starter, &team, &players), 975_000);
+        assert_eq!(price(best, &team, &players), 1_300_000);
+
+        // A longer deal and a rich club push the price up; a poor club sells cheap
+        let player = players.iter_mut().find(|p| p.id == bench).unwrap();
+        player.contract_expires = Some(Utc.with_ymd_and_hms(2029, 1, 1, 0, 0, 0).unwrap());
+        assert_eq!(price(bench, &team, &players), 800_000);
+        team.budget = 5_000_000;
+        assert_eq!(price(bench, &team, &players), 880_000);
+        team.budget = 500_000;
+        assert_eq!(price(bench, &team, &players), 680_000);
+    }
+}
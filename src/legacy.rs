@@ -0,0 +1,274 @@
+//! Reads the career eframe kept in its own storage before save slots
+//! existed. eframe stores values as RON, which is turned into the JSON the
+//! save migrations work on.
+
+use serde_json::{Map, Number, Value};
+
+/// Converts a RON document to JSON, with enums in serde's externally tagged
+/// form. Returns `None` if `ron` isn't valid RON.
+pub fn ron_to_json(ron: &str) -> Option<Value> {
+    let mut parser = Parser { bytes: ron.as_bytes(), pos: 0 };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    (parser.pos == parser.bytes.len()).then_some(value)
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|b| b.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    /// Skips whitespace and consumes `byte` if it's next.
+    fn eat(&mut self, byte: u8) -> bool {
+        self.skip_whitespace();
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn value(&mut self) -> Option<Value> {
+        self.skip_whitespace();
+        match self.peek()? {
+            b'"' => self.string().map(Value::String),
+            b'\'' => self.string().map(Value::String),
+            b'[' => {
+                self.pos += 1;
+                self.items(b']').map(Value::Array)
+            }
+            b'{' => {
+                self.pos += 1;
+                self.map()
+            }
+            b'(' => self.parenthesized(),
+            b'-' | b'+' | b'.' | b'0'..=b'9' => self.number(),
+            _ => {
+                let ident = self.identifier()?;
+                match ident {
+                    "true" => Some(Value::Bool(true)),
+                    "false" => Some(Value::Bool(false)),
+                    "None" => Some(Value::Null),
+                    "Some" => {
+                        self.skip_whitespace();
+                        self.parenthesized()
+                    }
+                    _ => {
+                        self.skip_whitespace();
+                        if self.peek() == Some(b'(') {
+                            // Newtype, tuple or struct variant
+                            let mut variant = Map::new();
+                            variant.insert(ident.to_string(), self.parenthesized()?);
+                            Some(Value::Object(variant))
+                        } else {
+                            // Unit variant
+                            Some(Value::String(ident.to_string()))
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// A struct `(name: value, ...)`, a tuple `(a, b)`, a newtype `(a)` or
+    /// the unit value `()`.
+    fn parenthesized(&mut self) -> Option<Value> {
+        if !self.eat(b'(') {
+            return None;
+        }
+        if self.eat(b')') {
+            return Some(Value::Null);
+        }
+
+        let start = self.pos;
+        self.skip_whitespace();
+        let is_struct = self.identifier().is_some() && self.eat(b':');
+        self.pos = start;
+
+        if is_struct {
+            let mut fields = Map::new();
+            loop {
+                self.skip_whitespace();
+                let name = self.identifier()?.to_string();
+                if !self.eat(b':') {
+                    return None;
+                }
+                fields.insert(name, self.value()?);
+                if self.eat(b')') {
+                    break;
+                }
+                if !self.eat(b',') {
+                    return None;
+                }
+                if self.eat(b')') {
+                    break;
+                }
+            }
+            return Some(Value::Object(fields));
+        }
+
+        let mut items = self.items(b')')?;
+        if items.len() == 1 {
+            items.pop()
+        } else {
+            Some(Value::Array(items))
+        }
+    }
+
+    /// Comma-separated values up to `close`, which may follow a trailing
+    /// comma.
+    fn items(&mut self, close: u8) -> Option<Vec<Value>> {
+        let mut items = Vec::new();
+        loop {
+            if self.eat(close) {
+                return Some(items);
+            }
+            items.push(self.value()?);
+            if !self.eat(b',') {
+                return self.eat(close).then_some(items);
+            }
+        }
+    }
+
+    fn map(&mut self) -> Option<Value> {
+        let mut entries = Map::new();
+        loop {
+            if self.eat(b'}') {
+                return Some(Value::Object(entries));
+            }
+            let key = match self.value()? {
+                Value::String(key) => key,
+                key => key.to_string(),
+            };
+            if !self.eat(b':') {
+                return None;
+            }
+            entries.insert(key, self.value()?);
+            if !self.eat(b',') {
+                return self.eat(b'}').then_some(Value::Object(entries));
+            }
+        }
+    }
+
+    fn identifier(&mut self) -> Option<&'a str> {
+        let bytes = self.bytes;
+        let start = self.pos;
+        while self.peek().is_some_and(|b| b.is_ascii_alphanumeric() || b == b'_') {
+            self.pos += 1;
+        }
+        if self.pos == start || bytes[start].is_ascii_digit() {
+            self.pos = start;
+            return None;
+        }
+        std::str::from_utf8(&bytes[start..self.pos]).ok()
+    }
+
+    fn number(&mut self) -> Option<Value> {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|b| b.is_ascii_digit() || matches!(b, b'-' | b'+' | b'.' | b'e' | b'E' | b'_'))
+        {
+            self.pos += 1;
+        }
+        let text: String = std::str::from_utf8(&self.bytes[start..self.pos])
+            .ok()?
+            .chars()
+            .filter(|&c| c != '_')
+            .collect();
+
+        if let Ok(n) = text.parse::<u64>() {
+            Some(Value::Number(n.into()))
+        } else if let Ok(n) = text.parse::<i64>() {
+            Some(Value::Number(n.into()))
+        } else {
+            text.parse::<f64>().ok().and_then(Number::from_f64).map(Value::Number)
+        }
+    }
+
+    /// A double-quoted string or a single-quoted char, with Rust escapes.
+    fn string(&mut self) -> Option<String> {
+        let quote = self.peek()?;
+        self.pos += 1;
+        let mut bytes = Vec::new();
+        loop {
+            let byte = self.peek()?;
+            self.pos += 1;
+            match byte {
+                b'\\' => {
+                    let escaped = self.peek()?;
+                    self.pos += 1;
+                    let c = match escaped {
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'0' => '\0',
+                        b'x' => {
+                            let hex = std::str::from_utf8(self.bytes.get(self.pos..self.pos + 2)?).ok()?;
+                            self.pos += 2;
+                            char::from(u8::from_str_radix(hex, 16).ok()?)
+                        }
+                        b'u' => {
+                            if !self.eat(b'{') {
+                                return None;
+                            }
+                            let end = self.pos + self.bytes[self.pos..].iter().position(|&b| b == b'}')?;
+                            let hex = std::str::from_utf8(&self.bytes[self.pos..end]).ok()?;
+                            self.pos = end + 1;
+                            char::from_u32(u32::from_str_radix(hex, 16).ok()?)?
+                        }
+                        other => char::from(other),
+                    };
+                    let mut buffer = [0; 4];
+                    bytes.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+                }
+                _ if byte == quote => return String::from_utf8(bytes).ok(),
+                _ => bytes.push(byte),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn converts_the_shapes_eframe_writes() {
+        let ron = r#"(team:Some((id:"a",players:["b","c"],coach:None)),morale:Average,injury:Wrist(3),
+            pair:(1,-2.5),map:{"Bind":4},empty:(),name:"Team \"A\"\u{e9}",ok:true,)"#;
+        assert_eq!(
+            ron_to_json(ron),
+            Some(json!({
+                "team": { "id": "a", "players": ["b", "c"], "coach": null },
+                "morale": "Average",
+                "injury": { "Wrist": 3 },
+                "pair": [1, -2.5],
+                "map": { "Bind": 4 },
+                "empty": null,
+                "name": "Team \"A\"é",
+                "ok": true,
+            }))
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_ron() {
+        for ron in ["", "(a:1", "(a 1)", "[1,2", "\"open", "(a:1) trailing"] {
+            assert_eq!(ron_to_json(ron), None, "{}", ron);
+        }
+    }
+}
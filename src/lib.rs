@@ -0,0 +1,8 @@
+//! Game logic for Valorant Manager, independent of any front end.
+//!
+//! Everything a career needs lives on [`game::GameState`]: create a world with
+//! `initialize_world` or `initialize_with_existing_team`, move time forward
+//! with `advance_day`/`advance_week`, play matches with `play_series`, and
+//! read the championship standings with `standings`.
+
+pub mod game;
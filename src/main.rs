@@ -2,7 +2,7 @@ use eframe::egui;
 use log::info;
 
 mod app;
-mod game;
+mod legacy;
 mod ui;
 
 use app::ValorantManagerApp;
@@ -1,14 +1,21 @@
-use crate::game::GameState;
+use valorant_manager::game::GameState;
 use eframe::egui;
+use crate::ui::NegotiationWindow;
 
-pub struct FinanceScreen;
+pub struct FinanceScreen {
+    negotiation: NegotiationWindow,
+}
 
 impl FinanceScreen {
     pub fn new() -> Self {
-        Self
+        Self {
+            negotiation: NegotiationWindow::new(),
+        }
     }
 
     pub fn show(&mut self, ctx: &egui::Context, game_state: &mut GameState) {
+        let mut negotiate_with: Option<uuid::Uuid> = None;
+
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.heading("Financial Management");
             ui.separator();
@@ -34,12 +41,7 @@ impl FinanceScreen {
                         ui.add_space(20.0);
                         ui.heading("Monthly Finances");
 
-                        let mut total_salaries = 0i64;
-                        for &player_id in &team.players {
-                            if let Some(player) = game_state.get_player_by_id(player_id) {
-                                total_salaries += player.contract_salary / 12;
-                            }
-                        }
+                        let total_salaries = game_state.monthly_wage_bill();
 
                         let facility_costs = 10_000i64;
                         let marketing_budget = 5_000i64;
@@ -124,20 +126,28 @@ impl FinanceScreen {
                         egui::ScrollArea::vertical().show(ui, |ui| {
                             for &player_id in &team.players {
                                 if let Some(player) = game_state.get_player_by_id(player_id) {
-                                    let unique_id = format!("finance_player_{}", player.id);
-
                                     ui.horizontal(|ui| {
                                         ui.label(&player.name);
                                         ui.label(format!("${}/year", player.contract_salary));
-                                        ui.label(format!("{} years left", player.contract_length));
+                                        ui.label(format!(
+                                            "{} seasons left",
+                                            player.contract_years_left(game_state.current_date)
+                                        ));
+                                        if player.starter_guarantee {
+                                            ui.label("Guaranteed starter");
+                                        }
                                     });
 
                                     ui.horizontal(|ui| {
                                         ui.label(format!("Market Value: ${}", player.market_value));
-                                        if ui.add(
-                                            egui::Button::new("Negotiate")
-                                        ).clicked() {
-                                            // TODO: Handle negotiate
+                                        let can_renew = player.in_final_year(game_state.current_date);
+                                        let in_talks = self.negotiation.is_open();
+                                        if ui
+                                            .add_enabled(can_renew && !in_talks, egui::Button::new("Negotiate"))
+                                            .on_disabled_hover_text("Renewal talks open in the contract's final season")
+                                            .clicked()
+                                        {
+                                            negotiate_with = Some(player_id);
                                         }
                                     });
 
@@ -164,14 +174,22 @@ impl FinanceScreen {
 
                         ui.add_space(20.0);
                         ui.heading("Financial Actions");
-                        ui.button("Request Loan");
-                        ui.button("Increase Marketing Budget");
-                        ui.button("Upgrade Facilities");
+                        ui.add_enabled_ui(false, |ui| {
+                            for action in ["Request Loan", "Increase Marketing Budget", "Upgrade Facilities"] {
+                                let _ = ui.button(action);
+                            }
+                        });
                     });
                 });
             } else {
                 ui.label("No team selected");
             }
         });
+
+        if let Some(player_id) = negotiate_with {
+            self.negotiation.open(game_state.open_negotiation(player_id));
+        }
+
+        self.negotiation.show(ctx, game_state);
     }
 }
@@ -1,5 +1,5 @@
 use eframe::egui;
-use crate::game::GameState;
+use valorant_manager::game::{GameState, SaveManager, SaveSummary};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,6 +19,9 @@ pub struct MainMenuScreen {
     new_game_step: NewGameStep,
     selected_league: Option<String>,
     selected_team: Option<TeamInfo>,
+    show_load_dialog: bool,
+    saves: Vec<SaveSummary>,
+    load_error: Option<String>,
 }
 
 impl MainMenuScreen {
@@ -28,6 +31,9 @@ impl MainMenuScreen {
             new_game_step: NewGameStep::LeagueSelection,
             selected_league: None,
             selected_team: None,
+            show_load_dialog: false,
+            saves: Vec::new(),
+            load_error: None,
         }
     }
 
@@ -97,7 +103,7 @@ impl MainMenuScreen {
         teams
     }
 
-    pub fn show(&mut self, ctx: &egui::Context, game_state: &mut GameState) -> bool {
+    pub fn show(&mut self, ctx: &egui::Context, game_state: &mut GameState, save_manager: &SaveManager) -> bool {
         let mut start_game = false;
 
         egui::CentralPanel::default().show(ctx, |ui| {
@@ -122,7 +128,9 @@ impl MainMenuScreen {
                 ui.add_space(10.0);
                 
                 if ui.add_sized([200.0, 50.0], egui::Button::new("Load Game")).clicked() {
-                    // TODO: Implement load game functionality
+                    self.show_load_dialog = true;
+                    self.saves = save_manager.list_saves();
+                    self.load_error = None;
                 }
                 
                 ui.add_space(10.0);
@@ -237,6 +245,86 @@ impl MainMenuScreen {
                 });
         }
 
+        if self.show_load_dialog && self.show_load_game_dialog(ctx, game_state, save_manager) {
+            start_game = true;
+        }
+
         start_game
     }
+
+    fn show_load_game_dialog(&mut self, ctx: &egui::Context, game_state: &mut GameState, save_manager: &SaveManager) -> bool {
+        let mut loaded = false;
+        let mut delete_slot: Option<String> = None;
+
+        egui::Window::new("Load Game")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                if self.saves.is_empty() {
+                    ui.label("No saved games found.");
+                    ui.label(format!("Saves are stored in {}", save_manager.dir().display()));
+                } else {
+                    egui::ScrollArea::vertical()
+                        .id_source("load_game_scroll")
+                        .max_height(300.0)
+                        .show(ui, |ui| {
+                            for save in &self.saves {
+                                ui.horizontal(|ui| {
+                                    ui.vertical(|ui| {
+                                        ui.strong(&save.slot);
+                                        ui.label(format!(
+                                            "{} - Season {} - {}",
+                                            save.team_name,
+                                            save.season,
+                                            save.game_date.format("%Y-%m-%d")
+                                        ));
+                                        ui.label(format!(
+                                            "Record: {}-{}  (saved {})",
+                                            save.wins,
+                                            save.losses,
+                                            save.saved_at.format("%Y-%m-%d %H:%M")
+                                        ));
+                                    });
+
+                                    if ui.button("Load").clicked() {
+                                        match save_manager.load(&save.slot) {
+                                            Ok(state) => {
+                                                *game_state = state;
+                                                loaded = true;
+                                            }
+                                            Err(err) => self.load_error = Some(err.to_string()),
+                                        }
+                                    }
+                                    if ui.button("Delete").clicked() {
+                                        delete_slot = Some(save.slot.clone());
+                                    }
+                                });
+                                ui.separator();
+                            }
+                        });
+                }
+
+                if let Some(error) = &self.load_error {
+                    ui.colored_label(egui::Color32::RED, error);
+                }
+
+                ui.add_space(10.0);
+                if ui.button("Cancel").clicked() {
+                    self.show_load_dialog = false;
+                }
+            });
+
+        if let Some(slot) = delete_slot {
+            if let Err(err) = save_manager.delete(&slot) {
+                self.load_error = Some(err.to_string());
+            }
+            self.saves = save_manager.list_saves();
+        }
+
+        if loaded {
+            self.show_load_dialog = false;
+        }
+        loaded
+    }
 } 
\ No newline at end of file
@@ -4,10 +4,12 @@ pub mod tactics;
 pub mod finance;
 pub mod schedule;
 pub mod scouting;
+pub mod negotiation;
 
 pub use main_menu::MainMenuScreen;
 pub use squad::SquadScreen;
 pub use tactics::TacticsScreen;
 pub use finance::FinanceScreen;
 pub use schedule::ScheduleScreen;
-pub use scouting::ScoutingScreen; 
\ No newline at end of file
+pub use scouting::ScoutingScreen;
+pub use negotiation::NegotiationWindow;
@@ -0,0 +1,127 @@
+use valorant_manager::game::{ContractOffer, GameState, Negotiation, NegotiationResponse};
+use eframe::egui;
+
+/// The window for talks with a player's agent, shared by contract renewals
+/// and transfers.
+pub struct NegotiationWindow {
+    negotiation: Option<Negotiation>,
+    offer: Option<ContractOffer>,
+    status: Option<String>,
+}
+
+impl NegotiationWindow {
+    pub fn new() -> Self {
+        Self {
+            negotiation: None,
+            offer: None,
+            status: None,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.negotiation.is_some()
+    }
+
+    pub fn open(&mut self, negotiation: Option<Negotiation>) {
+        self.offer = negotiation.as_ref().map(|n| n.demand.clone());
+        self.negotiation = negotiation;
+        self.status = None;
+    }
+
+    pub fn show(&mut self, ctx: &egui::Context, game_state: &mut GameState) {
+        let (Some(negotiation), Some(offer)) = (&mut self.negotiation, &mut self.offer) else {
+            // Talks are over; show how they ended until dismissed
+            let mut close = false;
+            if let Some(status) = &self.status {
+                egui::Window::new("Contract Negotiation").collapsible(false).show(ctx, |ui| {
+                    ui.label(status);
+                    close = ui.button("Close").clicked();
+                });
+            }
+            if close {
+                self.status = None;
+            }
+            return;
+        };
+        let player_name = game_state
+            .get_player_by_id(negotiation.player_id)
+            .map(|p| p.name.clone())
+            .unwrap_or_default();
+        let fee = negotiation.transfer.as_ref().map(|bid| bid.fee);
+
+        let mut make_offer = false;
+        let mut end_talks = false;
+        egui::Window::new("Contract Negotiation").collapsible(false).show(ctx, |ui| {
+            ui.heading(&player_name);
+            if let Some(fee) = fee {
+                ui.label(format!("Transfer fee agreed: ${}", fee));
+            }
+            ui.separator();
+
+            let demand = &negotiation.demand;
+            ui.label("Agent's demands:");
+            ui.label(format!("Salary: ${}/year", demand.salary));
+            ui.label(format!("Length: {} years", demand.length));
+            ui.label(format!("Starter guarantee: {}", if demand.starter_guarantee { "Yes" } else { "No" }));
+            ui.label(format!("Signing bonus: ${}", demand.signing_bonus));
+            ui.label(format!("Patience: {} offers left", negotiation.patience));
+
+            ui.add_space(10.0);
+            ui.label("Your offer:");
+            ui.horizontal(|ui| {
+                ui.label("Salary:");
+                ui.add(egui::DragValue::new(&mut offer.salary).speed(1000.0).range(0..=i64::MAX).prefix("$"));
+            });
+            ui.add(egui::Slider::new(&mut offer.length, 1..=5).text("years"));
+            ui.checkbox(&mut offer.starter_guarantee, "Guarantee a starting place");
+            ui.horizontal(|ui| {
+                ui.label("Signing bonus:");
+                ui.add(egui::DragValue::new(&mut offer.signing_bonus).speed(1000.0).range(0..=i64::MAX).prefix("$"));
+            });
+
+            if let Some(status) = &self.status {
+                ui.add_space(10.0);
+                ui.label(status);
+            }
+
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                if ui.button("Make Offer").clicked() {
+                    make_offer = true;
+                }
+                if ui.button("End Talks").clicked() {
+                    end_talks = true;
+                }
+            });
+        });
+
+        if make_offer {
+            self.status = Some(match game_state.submit_offer(negotiation, offer.clone()) {
+                None if fee.is_some() => "You can't afford the fee and signing bonus, or the deal is off".to_string(),
+                None => "You can't afford that signing bonus".to_string(),
+                Some(NegotiationResponse::Accepted) => {
+                    end_talks = true;
+                    match fee {
+                        Some(fee) => format!(
+                            "{} joins for ${} on ${}/year over {} years",
+                            player_name, fee, offer.salary, offer.length
+                        ),
+                        None => format!("{} has signed for ${}/year over {} years", player_name, offer.salary, offer.length),
+                    }
+                }
+                Some(NegotiationResponse::Countered(counter)) => {
+                    format!("The agent comes back asking ${}/year", counter.salary)
+                }
+                Some(NegotiationResponse::Rejected) => "The agent rejects your offer".to_string(),
+                Some(NegotiationResponse::WalkedAway) => {
+                    end_talks = true;
+                    format!("{}'s agent has walked away from talks", player_name)
+                }
+            });
+        }
+        if end_talks {
+            self.negotiation = None;
+            self.offer = None;
+        }
+    }
+}
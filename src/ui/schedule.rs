@@ -1,15 +1,37 @@
-use crate::game::GameState;
+use valorant_manager::game::{BuyPhase, ChampionshipStanding, GameState, MatchType, Series, SeriesFormat, Side, VetoAction};
 use eframe::egui;
+use rand::seq::SliceRandom;
+use uuid::Uuid;
 
-pub struct ScheduleScreen;
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum StandingsView {
+    Global,
+    Region,
+    CurrentEvent,
+}
+
+pub struct ScheduleScreen {
+    standings_view: StandingsView,
+    series_format: SeriesFormat,
+    pending_series: Option<Series>,
+    pending_fixture: Option<Uuid>, // Set when the pending series is a scheduled fixture
+}
 
 impl ScheduleScreen {
     pub fn new() -> Self {
-        Self
+        Self {
+            standings_view: StandingsView::Global,
+            series_format: SeriesFormat::BestOf3,
+            pending_series: None,
+            pending_fixture: None,
+        }
     }
 
     pub fn show(&mut self, ctx: &egui::Context, game_state: &mut GameState) {
         let mut advance_week = false;
+        let mut start_new_season = false;
+        let mut start_series = false;
+        let mut play_fixture = None;
 
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.heading("Schedule & Tournament");
@@ -23,7 +45,11 @@ impl ScheduleScreen {
 
                         ui.horizontal(|ui| {
                             ui.label("Current Event:");
-                            ui.label(game_state.tournament_state.get_current_event_name());
+                            if game_state.tournament_state.is_season_complete() {
+                                ui.label("Off-season");
+                            } else {
+                                ui.label(game_state.tournament_state.get_current_event_name());
+                            }
                         });
 
                         ui.horizontal(|ui| {
@@ -34,37 +60,58 @@ impl ScheduleScreen {
                         ui.add_space(20.0);
                         ui.heading("Championship Standings");
 
-                        egui::ScrollArea::vertical().show(ui, |ui| {
-                            for (i, standing) in game_state
-                                .tournament_state
-                                .championship_standings
-                                .iter()
-                                .enumerate()
-                            {
-                                if let Some(standing_team) = game_state
-                                    .all_teams
-                                    .iter()
-                                    .find(|t| t.id == standing.team_id)
-                                {
-                                    let is_current_team = standing.team_id == team.id;
-                                    let unique_id = format!("standing_{}", standing.team_id);
+                        ui.horizontal(|ui| {
+                            ui.selectable_value(&mut self.standings_view, StandingsView::Global, "Global");
+                            ui.selectable_value(&mut self.standings_view, StandingsView::Region, team.region.as_str());
+                            ui.selectable_value(&mut self.standings_view, StandingsView::CurrentEvent, "Current Event");
+                        });
 
-                                    ui.horizontal(|ui| {
-                                        ui.label(format!("{}.", i + 1));
-
-                                        let team_name = if is_current_team {
-                                            egui::RichText::new(&standing_team.name)
-                                                .color(egui::Color32::YELLOW)
-                                        } else {
-                                            egui::RichText::new(&standing_team.name)
-                                        };
-                                        ui.label(team_name);
-
-                                        ui.label(format!("{}pts", standing.points));
-                                        ui.label(format!("{}-{}", standing.wins, standing.losses));
-                                    });
-                                }
+                        let tournament = &game_state.tournament_state;
+                        let rows: Vec<&ChampionshipStanding> = match self.standings_view {
+                            StandingsView::Global => tournament.championship_standings.iter().collect(),
+                            StandingsView::Region => game_state
+                                .region_standings(&team.region)
+                                .into_iter()
+                                .map(|(_, standing)| standing)
+                                .collect(),
+                            StandingsView::CurrentEvent => tournament
+                                .event_tables(tournament.current_event)
+                                .find(|t| t.get_team_standing(team.id).is_some())
+                                .map(|t| t.standings.iter().collect())
+                                .unwrap_or_default(),
+                        };
+
+                        egui::ScrollArea::vertical().id_source("standings").max_height(300.0).show(ui, |ui| {
+                            if rows.is_empty() {
+                                ui.label("Not taking part in this event");
                             }
+                            egui::Grid::new("standings_grid").striped(true).show(ui, |ui| {
+                                ui.label("#");
+                                ui.label("Team");
+                                ui.label("Pts");
+                                ui.label("W-L");
+                                ui.label("Maps");
+                                ui.label("Rounds");
+                                ui.end_row();
+
+                                for (i, standing) in rows.into_iter().enumerate() {
+                                    let name = game_state
+                                        .get_team(standing.team_id)
+                                        .map(|t| t.name.as_str())
+                                        .unwrap_or("Unknown");
+                                    ui.label(format!("{}.", i + 1));
+                                    if standing.team_id == team.id {
+                                        ui.label(egui::RichText::new(name).color(egui::Color32::YELLOW));
+                                    } else {
+                                        ui.label(name);
+                                    }
+                                    ui.label(standing.points.to_string());
+                                    ui.label(format!("{}-{}", standing.wins, standing.losses));
+                                    ui.label(format!("{:+}", standing.map_differential()));
+                                    ui.label(format!("{:+}", standing.round_differential()));
+                                    ui.end_row();
+                                }
+                            });
                         });
 
                         ui.add_space(20.0);
@@ -87,6 +134,42 @@ impl ScheduleScreen {
                         } else {
                             ui.colored_label(egui::Color32::RED, "✗ Not qualified for Champions");
                         }
+
+                        ui.add_space(20.0);
+                        ui.heading("Event History");
+
+                        if game_state.tournament_state.history.is_empty() {
+                            ui.label("No international events played yet");
+                        }
+                        for result in game_state.tournament_state.history.iter().rev() {
+                            let champion = game_state
+                                .get_team(result.champion_id)
+                                .map(|t| t.name.as_str())
+                                .unwrap_or("Unknown");
+                            ui.label(format!("{} {}: {}", result.event.name(), result.season, champion));
+                            if let Some(placement) = result.placements.iter().find(|p| p.team_id == team.id) {
+                                ui.label(
+                                    egui::RichText::new(format!(
+                                        "You finished #{} (+{}pts, ${})",
+                                        placement.place, placement.points, placement.prize_money
+                                    ))
+                                    .small(),
+                                );
+                            }
+                        }
+
+                        ui.add_space(20.0);
+                        ui.heading("News");
+
+                        if game_state.news.is_empty() {
+                            ui.label("No news yet");
+                        }
+                        for item in game_state.news.iter().rev().take(10) {
+                            ui.horizontal(|ui| {
+                                ui.label(egui::RichText::new(item.date.format("%Y-%m-%d").to_string()).small());
+                                ui.label(&item.headline);
+                            });
+                        }
                     });
 
                     ui.separator();
@@ -94,7 +177,33 @@ impl ScheduleScreen {
                     // Right panel - Upcoming matches & results
                     ui.vertical(|ui| {
                         ui.heading("Upcoming Matches");
-                        ui.label("No upcoming matches scheduled");
+
+                        let upcoming: Vec<_> = game_state
+                            .tournament_state
+                            .team_fixtures(team.id)
+                            .filter(|f| !f.completed)
+                            .take(5)
+                            .collect();
+                        if upcoming.is_empty() {
+                            ui.label("No upcoming matches scheduled");
+                        }
+                        for fixture in upcoming {
+                            let opponent = game_state
+                                .get_team(fixture.opponent(team.id))
+                                .map(|t| t.name.as_str())
+                                .unwrap_or("Unknown");
+                            ui.horizontal(|ui| {
+                                ui.label(fixture.scheduled_date.format("%Y-%m-%d").to_string());
+                                ui.label(format!("vs {} ({})", opponent, fixture.format.name()));
+                                if fixture.scheduled_date <= game_state.current_date
+                                    && ui.button("Play Match").clicked()
+                                    && self.pending_series.is_none()
+                                {
+                                    play_fixture = Some(fixture.id);
+                                }
+                            });
+                            ui.label(egui::RichText::new(&fixture.label).small());
+                        }
 
                         ui.add_space(20.0);
                         ui.heading("Recent Results");
@@ -107,15 +216,11 @@ impl ScheduleScreen {
                                     let unique_id = format!("match_{}", match_result.id);
                                     ui.horizontal(|ui| {
                                         let team1_name = game_state
-                                            .all_teams
-                                            .iter()
-                                            .find(|t| t.id == match_result.team1_id)
+                                            .get_team(match_result.team1_id)
                                             .map(|t| t.name.as_str())
                                             .unwrap_or("Unknown");
                                         let team2_name = game_state
-                                            .all_teams
-                                            .iter()
-                                            .find(|t| t.id == match_result.team2_id)
+                                            .get_team(match_result.team2_id)
                                             .map(|t| t.name.as_str())
                                             .unwrap_or("Unknown");
 
@@ -128,6 +233,25 @@ impl ScheduleScreen {
                                         ));
                                         ui.label(&match_result.map);
                                     });
+                                    egui::CollapsingHeader::new("Economy")
+                                        .id_source(&unique_id)
+                                        .show(ui, |ui| {
+                                            for team_id in [match_result.team1_id, match_result.team2_id] {
+                                                let nickname = game_state
+                                                    .get_team(team_id)
+                                                    .map(|t| t.nickname.as_str())
+                                                    .unwrap_or("Unknown");
+                                                ui.label(format!(
+                                                    "{}: {} credits spent, {} full buys, {} half buys, {} force buys, {} ecos",
+                                                    nickname,
+                                                    match_result.credits_spent(team_id),
+                                                    match_result.buy_count(team_id, BuyPhase::FullBuy),
+                                                    match_result.buy_count(team_id, BuyPhase::HalfBuy),
+                                                    match_result.buy_count(team_id, BuyPhase::ForceBuy),
+                                                    match_result.buy_count(team_id, BuyPhase::EcoRound),
+                                                ));
+                                            }
+                                        });
                                     ui.separator();
                                 }
                             });
@@ -135,7 +259,7 @@ impl ScheduleScreen {
 
                         ui.add_space(20.0);
                         ui.heading("Season Calendar");
-                        ui.label("VCT 2025 Schedule:");
+                        ui.label(format!("VCT {} Schedule:", game_state.current_season));
                         ui.label("• January-February: Kickoff");
                         ui.label("• February-March: Masters Bangkok");
                         ui.label("• March-May: Stage 1");
@@ -146,12 +270,31 @@ impl ScheduleScreen {
                         ui.add_space(20.0);
                         ui.heading("Team Actions");
 
-                        if ui.button("Advance Week").clicked() {
+                        let match_due = game_state.user_fixture_due().is_some();
+                        if game_state.is_rollover_due() {
+                            let label = format!("Start {} Season", game_state.current_season + 1);
+                            if ui.button(label).clicked() {
+                                start_new_season = true;
+                            }
+                        } else if ui
+                            .add_enabled(!match_due, egui::Button::new("Advance Week"))
+                            .on_disabled_hover_text("Play today's match first")
+                            .clicked()
+                        {
                             advance_week = true;
                         }
-                        if ui.button("Simulate Match").clicked() {
-                            // TODO
-                        }
+                        ui.horizontal(|ui| {
+                            egui::ComboBox::from_id_source("series_format")
+                                .selected_text(self.series_format.name())
+                                .show_ui(ui, |ui| {
+                                    for format in [SeriesFormat::BestOf1, SeriesFormat::BestOf3, SeriesFormat::BestOf5] {
+                                        ui.selectable_value(&mut self.series_format, format, format.name());
+                                    }
+                                });
+                            if ui.button("Play Series").clicked() && self.pending_series.is_none() {
+                                start_series = true;
+                            }
+                        });
 
                         ui.add_space(10.0);
                         ui.horizontal(|ui| {
@@ -166,10 +309,165 @@ impl ScheduleScreen {
         });
 
         if advance_week {
-            game_state.tournament_state.advance_week();
-            for _ in 0..7 {
-                game_state.advance_day();
+            game_state.advance_week();
+        }
+        if start_new_season {
+            game_state.start_new_season();
+        }
+
+        if start_series {
+            self.pending_series = Self::create_friendly(game_state, self.series_format);
+            self.pending_fixture = None;
+        }
+        if let Some(fixture_id) = play_fixture {
+            self.pending_series = game_state.fixture_series(fixture_id);
+            self.pending_fixture = Some(fixture_id);
+        }
+
+        self.show_veto_window(ctx, game_state);
+    }
+
+    fn create_friendly(game_state: &mut GameState, format: SeriesFormat) -> Option<Series> {
+        let team = game_state.current_team.as_ref()?;
+
+        // Prefer an opponent from the same league
+        let regional: Vec<_> = game_state
+            .all_teams
+            .iter()
+            .filter(|t| t.region == team.region)
+            .map(|t| t.id)
+            .collect();
+        let candidates: Vec<_> = if regional.is_empty() {
+            game_state.all_teams.iter().map(|t| t.id).collect()
+        } else {
+            regional
+        };
+
+        let team_id = team.id;
+        let opponent_id = *candidates.choose(&mut game_state.rng)?;
+        Some(Series::new(team_id, opponent_id, format, MatchType::Regular, &mut game_state.rng))
+    }
+
+    fn show_veto_window(&mut self, ctx: &egui::Context, game_state: &mut GameState) {
+        let Some(series) = &mut self.pending_series else {
+            return;
+        };
+        let Some(user_team_id) = game_state.current_team.as_ref().map(|t| t.id) else {
+            return;
+        };
+
+        // Let the opponent take its turns straight away
+        if let Some((team_id, _)) = series.next_veto_action() {
+            if team_id != user_team_id {
+                if let Some(opponent) = game_state.get_team(team_id) {
+                    series.ai_veto_step(opponent);
+                }
+            }
+        }
+
+        let team_name = |id| {
+            game_state
+                .get_team(id)
+                .map(|t| t.nickname.clone())
+                .unwrap_or_else(|| "Unknown".to_string())
+        };
+
+        let mut simulate = false;
+        let mut cancel = false;
+
+        egui::Window::new(format!(
+            "Map Veto: {} vs {} ({})",
+            team_name(series.team1_id),
+            team_name(series.team2_id),
+            series.format.name()
+        ))
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            for step in &series.veto {
+                let text = match (step.action, step.side) {
+                    (VetoAction::Ban, _) => format!("{} banned {}", team_name(step.team_id), step.map),
+                    (VetoAction::Pick, _) => format!("{} picked {}", team_name(step.team_id), step.map),
+                    (VetoAction::Decider, _) => format!("{} is the decider", step.map),
+                    (VetoAction::ChooseSide, side) => format!(
+                        "{} starts on {:?} on {}",
+                        team_name(step.team_id),
+                        side.unwrap_or(Side::Attack),
+                        step.map
+                    ),
+                };
+                ui.label(text);
+            }
+
+            ui.add_space(10.0);
+
+            match series.next_veto_action() {
+                Some((team_id, action)) if team_id == user_team_id => match action {
+                    VetoAction::Ban | VetoAction::Pick => {
+                        ui.label(if action == VetoAction::Ban { "Ban a map:" } else { "Pick a map:" });
+                        ui.horizontal_wrapped(|ui| {
+                            for map in series.remaining_maps() {
+                                let proficiency = game_state
+                                    .get_team(user_team_id)
+                                    .map(|t| t.get_map_proficiency(map))
+                                    .unwrap_or(0);
+                                if ui.button(format!("{} ({})", map, proficiency)).clicked() {
+                                    series.veto_map(map);
+                                }
+                            }
+                        });
+                    }
+                    VetoAction::ChooseSide => {
+                        let map = series.maps.last().map(|m| m.map.clone()).unwrap_or_default();
+                        ui.label(format!("Choose your starting side on {}:", map));
+                        ui.horizontal(|ui| {
+                            if ui.button("Attack").clicked() {
+                                series.choose_side(Side::Attack);
+                            }
+                            if ui.button("Defense").clicked() {
+                                series.choose_side(Side::Defense);
+                            }
+                        });
+                    }
+                    VetoAction::Decider => {}
+                },
+                Some(_) => {
+                    ui.label("Waiting for opponent...");
+                }
+                None => {
+                    ui.label("Veto complete:");
+                    for (i, series_map) in series.maps.iter().enumerate() {
+                        ui.label(format!(
+                            "Map {}: {} ({} starts on {:?})",
+                            i + 1,
+                            series_map.map,
+                            team_name(series.team1_id),
+                            series_map.team1_starting_side
+                        ));
+                    }
+                    if ui.button("Simulate Series").clicked() {
+                        simulate = true;
+                    }
+                }
+            }
+
+            ui.add_space(10.0);
+            if ui.button("Cancel").clicked() {
+                cancel = true;
+            }
+        });
+
+        if simulate {
+            if let Some(series) = self.pending_series.take() {
+                match self.pending_fixture.take() {
+                    Some(fixture_id) => game_state.play_fixture(fixture_id, series),
+                    None => game_state.play_series(series),
+                };
             }
+        } else if cancel {
+            self.pending_series = None;
+            self.pending_fixture = None;
         }
     }
 }
@@ -1,11 +1,19 @@
-use crate::game::{AgentRole, GameState, Player};
+use valorant_manager::game::{
+    AgentRole, BidError, GameState, Player, TransferKind, TransferResponse, SCOUTING_UPGRADE_COST,
+};
 use eframe::egui;
 use rand::Rng;
+use crate::ui::NegotiationWindow;
 
 pub struct ScoutingScreen {
-    scouted_players: Vec<Player>,
+    scouted_players: Vec<uuid::Uuid>,
     selected_player_id: Option<uuid::Uuid>,
     scouting_budget: i64,
+    market_team_id: Option<uuid::Uuid>,
+    bid_player_id: Option<uuid::Uuid>,
+    bid_fee: i64,
+    bid_status: Option<String>,
+    negotiation: NegotiationWindow,
 }
 
 impl ScoutingScreen {
@@ -14,12 +22,23 @@ impl ScoutingScreen {
             scouted_players: Vec::new(),
             selected_player_id: None,
             scouting_budget: 50_000, // Starting scouting budget
+            market_team_id: None,
+            bid_player_id: None,
+            bid_fee: 0,
+            bid_status: None,
+            negotiation: NegotiationWindow::new(),
         }
     }
 
     pub fn show(&mut self, ctx: &egui::Context, game_state: &mut GameState) {
         let mut scout_new_players = false;
+        let mut upgrade_scouting = false;
         let mut sign_player_id: Option<uuid::Uuid> = None;
+        let mut make_bid = false;
+
+        // Anyone who has signed somewhere since being scouted drops off the list
+        let free_agents: Vec<uuid::Uuid> = game_state.free_agents().map(|p| p.id).collect();
+        self.scouted_players.retain(|id| free_agents.contains(id));
 
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.heading("Scouting & Transfers");
@@ -39,6 +58,18 @@ impl ScoutingScreen {
                             ui.label(format!("${}", self.scouting_budget));
                         });
 
+                        ui.horizontal(|ui| {
+                            ui.label("Scout Accuracy:");
+                            ui.label(format!("{}%", game_state.scouting.accuracy));
+                        });
+
+                        let can_upgrade = game_state.scouting.accuracy < 100 && game_state.budget >= SCOUTING_UPGRADE_COST;
+                        ui.add_enabled_ui(can_upgrade, |ui| {
+                            if ui.button(format!("Hire More Scouts (${})", SCOUTING_UPGRADE_COST)).clicked() {
+                                upgrade_scouting = true;
+                            }
+                        });
+
                         ui.add_space(10.0);
 
                         let scout_button_text = if self.scouted_players.is_empty() {
@@ -64,8 +95,8 @@ impl ScoutingScreen {
                             ui.label("No players scouted yet. Click 'Scout New Players' to discover talent.");
                         } else {
                             // Show all scouted players without scrolling - similar to squad screen
-                            for &player_id in &self.scouted_players.iter().map(|p| p.id).collect::<Vec<_>>() {
-                                if let Some(player) = self.scouted_players.iter().find(|p| p.id == player_id) {
+                            for &player_id in &self.scouted_players {
+                                if let Some(player) = game_state.get_player_by_id(player_id) {
                                     let is_selected = self.selected_player_id == Some(player_id);
 
                                     // Create the content area with non-selectable text first to get actual size
@@ -86,6 +117,10 @@ impl ScoutingScreen {
                                                         player.attributes.overall_rating()
                                                     )).selectable(false));
                                                     ui.add(egui::Label::new(format!("Age: {}", player.age)).selectable(false));
+                                                    ui.add(egui::Label::new(format!(
+                                                        "Potential: {}",
+                                                        Self::potential_label(game_state, player)
+                                                    )).selectable(false));
                                                 });
 
                                                 ui.horizontal(|ui| {
@@ -94,18 +129,10 @@ impl ScoutingScreen {
                                                 });
 
                                                 ui.horizontal(|ui| {
-                                                    let can_afford = game_state.budget >= player.market_value;
-                                                    let button_text = if can_afford {
-                                                        format!("Sign for ${}", player.market_value)
-                                                    } else {
-                                                        "Can't Afford".to_string()
-                                                    };
-                                                    
-                                                    ui.add_enabled_ui(can_afford, |ui| {
-                                                        if ui.small_button(button_text).clicked() {
-                                                            sign_player_id = Some(player_id);
-                                                        }
-                                                    });
+                                                    let in_talks = self.negotiation.is_open();
+                                                    if ui.add_enabled(!in_talks, egui::Button::new("Negotiate").small()).clicked() {
+                                                        sign_player_id = Some(player_id);
+                                                    }
                                                 });
                                                 ui.add_space(3.0);
                                             });
@@ -157,6 +184,91 @@ impl ScoutingScreen {
                             }
                         }
 
+                        ui.add_space(20.0);
+                        let mut free_agents: Vec<&Player> = game_state.free_agents().collect();
+                        free_agents.sort_by_key(|p| std::cmp::Reverse(p.attributes.overall_rating()));
+                        egui::CollapsingHeader::new(format!("Free Agent Pool ({})", free_agents.len()))
+                            .id_source("free_agent_pool")
+                            .show(ui, |ui| {
+                                egui::ScrollArea::vertical().id_source("free_agents").max_height(250.0).show(ui, |ui| {
+                                    for player in free_agents {
+                                        ui.horizontal(|ui| {
+                                            let is_selected = self.selected_player_id == Some(player.id);
+                                            if ui.selectable_label(is_selected, &player.name).clicked() {
+                                                self.selected_player_id = Some(player.id);
+                                            }
+                                            ui.label(format!(
+                                                "{:?}, {} yrs, OVR {}, POT {}",
+                                                player.preferred_role,
+                                                player.age,
+                                                player.attributes.overall_rating(),
+                                                Self::potential_label(game_state, player)
+                                            ));
+                                        });
+                                    }
+                                });
+                            });
+
+                        ui.add_space(10.0);
+                        egui::CollapsingHeader::new("Transfer Market")
+                            .id_source("transfer_market")
+                            .show(ui, |ui| {
+                                let selected_club = self.market_team_id.and_then(|id| game_state.get_team(id));
+                                egui::ComboBox::from_id_source("market_team")
+                                    .selected_text(selected_club.map_or("Choose a club", |t| t.name.as_str()))
+                                    .show_ui(ui, |ui| {
+                                        for team in &game_state.all_teams {
+                                            ui.selectable_value(&mut self.market_team_id, Some(team.id), &team.name);
+                                        }
+                                    });
+
+                                if let Some(club) = selected_club {
+                                    for player in club.players.iter().filter_map(|&id| game_state.get_player_by_id(id)) {
+                                        ui.horizontal(|ui| {
+                                            let is_selected = self.selected_player_id == Some(player.id);
+                                            if ui.selectable_label(is_selected, &player.name).clicked() {
+                                                self.selected_player_id = Some(player.id);
+                                            }
+                                            ui.label(format!(
+                                                "{:?}, {} yrs, OVR {}, ${}",
+                                                player.preferred_role,
+                                                player.age,
+                                                player.attributes.overall_rating(),
+                                                player.market_value
+                                            ));
+                                        });
+                                    }
+                                }
+                            });
+
+                        ui.add_space(10.0);
+                        egui::CollapsingHeader::new("Recent Transfers")
+                            .id_source("recent_transfers")
+                            .show(ui, |ui| {
+                                let team_name = |id: Option<uuid::Uuid>| {
+                                    id.and_then(|id| game_state.get_team(id))
+                                        .map_or("Free agency", |t| t.name.as_str())
+                                };
+                                let recent: Vec<_> = game_state.transfers.iter().rev().take(10).collect();
+                                if recent.is_empty() {
+                                    ui.label("No transfers yet");
+                                }
+                                for transfer in recent {
+                                    let fee = match transfer.kind {
+                                        TransferKind::Transfer => format!("${}", transfer.fee),
+                                        kind => kind.name().to_string(),
+                                    };
+                                    ui.label(format!(
+                                        "{}  {}: {} -> {} ({})",
+                                        transfer.date.format("%Y-%m-%d"),
+                                        transfer.player_name,
+                                        team_name(transfer.from_team),
+                                        team_name(transfer.to_team),
+                                        fee
+                                    ));
+                                }
+                            });
+
                         ui.add_space(20.0);
                         ui.heading("Scouting Regions");
                         ui.label("• North America");
@@ -175,7 +287,7 @@ impl ScoutingScreen {
                         egui::Layout::top_down(egui::Align::LEFT),
                         |ui| {
                         if let Some(player_id) = self.selected_player_id {
-                            if let Some(player) = self.scouted_players.iter().find(|p| p.id == player_id) {
+                            if let Some(player) = game_state.get_player_by_id(player_id) {
                                 ui.heading(&player.name);
                                 ui.separator();
 
@@ -189,6 +301,11 @@ impl ScoutingScreen {
                                     ui.label(format!("Overall Rating: {}", player.attributes.overall_rating()));
                                 });
 
+                                ui.horizontal(|ui| {
+                                    ui.label(format!("Potential: {}", Self::potential_label(game_state, player)));
+                                    ui.label(format!("Scouting Knowledge: {}%", game_state.scouting.knowledge(player.id)));
+                                });
+
                                 ui.horizontal(|ui| {
                                     ui.label(format!("Market Value: ${}", player.market_value));
                                     ui.label(format!("Salary Demand: ${}/year", player.contract_salary));
@@ -228,23 +345,51 @@ impl ScoutingScreen {
                                     });
                                 }
 
-                                ui.add_space(20.0);
+                                ui.add_space(10.0);
+                                ui.heading("Best Agents");
 
-                                let can_afford = game_state.budget >= player.market_value;
-                                let button_text = if can_afford {
-                                    format!("Sign for ${}", player.market_value)
-                                } else {
-                                    "Cannot Afford".to_string()
-                                };
+                                for mastery in player.best_agents(3) {
+                                    ui.label(format!("{} ({:?}): {}/20", mastery.agent.name(), mastery.agent.role(), mastery.proficiency));
+                                }
+
+                                ui.add_space(20.0);
 
-                                ui.add_enabled_ui(can_afford, |ui| {
-                                    if ui.button(button_text).clicked() {
+                                let club = game_state.all_teams.iter().find(|t| t.players.contains(&player.id));
+                                let on_user_team = game_state
+                                    .current_team
+                                    .as_ref()
+                                    .is_some_and(|t| t.players.contains(&player.id));
+                                let loan = game_state.loans.iter().find(|l| l.player_id == player.id);
+                                if let Some(loan) = loan {
+                                    let parent = game_state.get_team(loan.from_team).map_or("", |t| t.name.as_str());
+                                    ui.label(format!("On loan from {} until {}", parent, loan.return_date.format("%Y-%m-%d")));
+                                } else if let Some(club) = club {
+                                    if self.bid_player_id != Some(player.id) {
+                                        self.bid_player_id = Some(player.id);
+                                        self.bid_fee = player.market_value;
+                                        self.bid_status = None;
+                                    }
+                                    ui.label(format!("Under contract with {}", club.name));
+                                    if let Some(expires) = player.contract_expires {
+                                        ui.label(format!("Contract expires: {}", expires.format("%Y-%m-%d")));
+                                    }
+                                    ui.horizontal(|ui| {
+                                        ui.label("Transfer fee:");
+                                        ui.add(egui::DragValue::new(&mut self.bid_fee).speed(10_000.0).range(0..=i64::MAX).prefix("$"));
+                                        let in_talks = self.negotiation.is_open();
+                                        if ui.add_enabled(!in_talks, egui::Button::new("Make Offer")).clicked() {
+                                            make_bid = true;
+                                        }
+                                    });
+                                    if let Some(status) = &self.bid_status {
+                                        ui.label(status);
+                                    }
+                                } else if !on_user_team {
+                                    ui.label("Free agent: no transfer fee");
+                                    let in_talks = self.negotiation.is_open();
+                                    if ui.add_enabled(!in_talks, egui::Button::new("Negotiate")).clicked() {
                                         sign_player_id = Some(player.id);
                                     }
-                                });
-
-                                if !can_afford {
-                                    ui.colored_label(egui::Color32::RED, "Insufficient budget");
                                 }
                             }
                         } else {
@@ -257,47 +402,75 @@ impl ScoutingScreen {
             }
         });
 
+        if upgrade_scouting {
+            game_state.upgrade_scouting();
+        }
+
         if scout_new_players {
-            self.scout_new_players();
+            self.scout_new_players(game_state);
         }
 
         if let Some(player_id) = sign_player_id {
             self.sign_player(player_id, game_state);
         }
+
+        if make_bid {
+            self.make_bid(game_state);
+        }
+
+        self.negotiation.show(ctx, game_state);
+    }
+
+    /// Puts the current bid to the player's club. Once a fee is agreed,
+    /// talks move on to the player's personal terms.
+    fn make_bid(&mut self, game_state: &mut GameState) {
+        let Some(player_id) = self.bid_player_id else {
+            return;
+        };
+        let club_name = game_state
+            .all_teams
+            .iter()
+            .find(|t| t.players.contains(&player_id))
+            .map(|t| t.name.clone())
+            .unwrap_or_default();
+        self.bid_status = Some(match game_state.bid_for_player(player_id, self.bid_fee) {
+            Err(BidError::NoTeam) => "You need a team to make bids".to_string(),
+            Err(BidError::InvalidFee) => "Enter a fee above $0".to_string(),
+            Err(BidError::CantAfford) => "You can't afford that fee".to_string(),
+            Err(BidError::OnLoan) => "That player is out on loan and can't be sold until the loan ends".to_string(),
+            Err(BidError::NotAtAClub) => "That player is no longer under contract with a club".to_string(),
+            Ok(TransferResponse::Accepted) => {
+                self.negotiation.open(game_state.open_transfer_negotiation(player_id, self.bid_fee));
+                format!("{} accept ${}; now agree personal terms", club_name, self.bid_fee)
+            }
+            Ok(TransferResponse::Countered(price)) => {
+                self.bid_fee = price;
+                format!("{} would sell for ${}", club_name, price)
+            }
+            Ok(TransferResponse::Rejected) => format!("{} reject the bid out of hand", club_name),
+        });
     }
 
-    fn scout_new_players(&mut self) {
+    /// The scouts' estimate of a player's potential, as a range until
+    /// they're sure.
+    fn potential_label(game_state: &GameState, player: &Player) -> String {
+        match game_state.scouting.potential_estimate(player) {
+            (low, high) if low == high => low.to_string(),
+            (low, high) => format!("{}-{}", low, high),
+        }
+    }
+
+    fn scout_new_players(&mut self, game_state: &mut GameState) {
         if self.scouting_budget >= 10_000 {
             self.scouting_budget -= 10_000;
-            
-            // Clear the existing list to refresh with new players
-            self.scouted_players.clear();
             self.selected_player_id = None; // Clear selection since list is refreshed
-            
-            let mut rng = rand::thread_rng();
-            let num_players = rng.gen_range(3..=5); // Generate 3-5 players
-            for i in 0..num_players {
-                let player = Player::generate_random(format!(
-                    "Scout{}{}",
-                    i + 1,
-                    rng.gen_range(100..999)
-                ));
-                self.scouted_players.push(player);
-            }
+
+            let num_players = game_state.rng.gen_range(3..=5); // Scout 3-5 players
+            self.scouted_players = game_state.scout_free_agents(num_players);
         }
     }
 
     fn sign_player(&mut self, player_id: uuid::Uuid, game_state: &mut GameState) {
-        if let Some(player_index) = self.scouted_players.iter().position(|p| p.id == player_id) {
-            let player = self.scouted_players.remove(player_index);
-            if game_state.budget >= player.market_value {
-                game_state.budget -= player.market_value;
-                if let Some(team) = &mut game_state.current_team {
-                    team.add_player(player.id);
-                }
-                game_state.all_players.push(player);
-                self.selected_player_id = None;
-            }
-        }
+        self.negotiation.open(game_state.open_free_agent_negotiation(player_id));
     }
 }
@@ -1,6 +1,17 @@
-use crate::game::{AgentRole, GameState};
+use valorant_manager::game::{
+    termination_payout, AgentRole, GameState, Player, Team, TrainingFocus, TrainingIntensity, TrainingSchedule,
+    MIN_ROSTER_SIZE,
+};
 use eframe::egui;
 
+enum TransferAction {
+    SetListed(uuid::Uuid, bool),
+    LoanOut(uuid::Uuid),
+    Release(uuid::Uuid),
+    AcceptBid(uuid::Uuid),
+    RejectBid(uuid::Uuid),
+}
+
 pub struct SquadScreen {
     selected_player_id: Option<uuid::Uuid>,
     starters_collapsed: bool,
@@ -23,6 +34,9 @@ impl SquadScreen {
     }
 
     pub fn show(&mut self, ctx: &egui::Context, game_state: &mut GameState) {
+        let mut training_change: Option<(uuid::Uuid, TrainingSchedule)> = None;
+        let mut transfer_action: Option<TransferAction> = None;
+
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.heading("Squad Management");
             ui.separator();
@@ -81,7 +95,18 @@ impl SquadScreen {
                         if !self.bench_collapsed {
                             self.show_player_list(ui, &mut team, game_state, false);
                         }
-                    }).response;
+
+                        let loans: Vec<_> = game_state.loans.iter().filter(|l| l.from_team == team.id).collect();
+                        if !loans.is_empty() {
+                            ui.add_space(10.0);
+                            ui.label(egui::RichText::new("Out on Loan").strong());
+                            for loan in loans {
+                                let name = game_state.get_player_by_id(loan.player_id).map_or("", |p| p.name.as_str());
+                                let club = game_state.get_team(loan.to_team).map_or("", |t| t.name.as_str());
+                                ui.label(format!("{} at {} until {}", name, club, loan.return_date.format("%Y-%m-%d")));
+                            }
+                        }
+                    });
 
                     ui.separator();
 
@@ -185,15 +210,139 @@ impl SquadScreen {
                                     });
                                 }
 
+                                ui.add_space(10.0);
+                                ui.heading("Agent Pool");
+
+                                for mastery in player.best_agents(5) {
+                                    let progress = mastery.proficiency as f32 / 20.0;
+                                    ui.horizontal(|ui| {
+                                        ui.label(format!("{} ({:?}):", mastery.agent.name(), mastery.agent.role()));
+                                        ui.add(
+                                            egui::ProgressBar::new(progress)
+                                                .text(format!("{}", mastery.proficiency)),
+                                        );
+                                    });
+                                }
+
+                                ui.add_space(10.0);
+                                ui.heading("Training");
+
+                                let mut schedule = player.training;
+                                ui.horizontal(|ui| {
+                                    egui::ComboBox::from_label("Focus")
+                                        .selected_text(schedule.focus.name())
+                                        .show_ui(ui, |ui| {
+                                            for focus in TrainingFocus::ALL {
+                                                ui.selectable_value(&mut schedule.focus, focus, focus.name());
+                                            }
+                                        });
+                                    egui::ComboBox::from_label("Intensity")
+                                        .selected_text(schedule.intensity.name())
+                                        .show_ui(ui, |ui| {
+                                            for intensity in TrainingIntensity::ALL {
+                                                ui.selectable_value(&mut schedule.intensity, intensity, intensity.name());
+                                            }
+                                        });
+                                });
+                                if schedule != player.training {
+                                    training_change = Some((player_id, schedule));
+                                }
+                                ui.horizontal(|ui| {
+                                    ui.label(format!("Training Happiness: {:+}", player.training_happiness));
+                                    ui.label(format!("Condition: {}%", player.condition()));
+                                });
+                                if player.break_days > 0 {
+                                    ui.colored_label(
+                                        egui::Color32::RED,
+                                        format!("Burnt out: on a break for {} more days", player.break_days),
+                                    );
+                                }
+
+                                if let Some(injury) = &player.injury {
+                                    ui.add_space(10.0);
+                                    ui.heading("Medical");
+                                    if !injury.recovered {
+                                        ui.colored_label(
+                                            egui::Color32::RED,
+                                            format!("{} ({})", injury.kind.name(), injury.severity.name()),
+                                        );
+                                        ui.label(format!("Expected return: {}", injury.return_date.format("%Y-%m-%d")));
+                                    } else if injury.can_relapse(game_state.current_date) {
+                                        ui.colored_label(
+                                            egui::Color32::YELLOW,
+                                            format!("Back from {}: at risk of a relapse", injury.kind.name().to_lowercase()),
+                                        );
+                                    }
+                                }
+
                                 ui.add_space(20.0);
                                 ui.heading("Contract");
                                 ui.horizontal(|ui| {
                                     ui.label(format!("Salary: ${}/year", player.contract_salary));
-                                    ui.label(format!(
-                                        "Contract Length: {} years",
-                                        player.contract_length
-                                    ));
+                                    match player.contract_expires {
+                                        Some(expires) => ui.label(format!(
+                                            "Expires: {} ({} seasons left)",
+                                            expires.format("%Y-%m-%d"),
+                                            player.contract_years_left(game_state.current_date)
+                                        )),
+                                        None => ui.label("No contract"),
+                                    };
+                                });
+                                if player.in_final_year(game_state.current_date) {
+                                    ui.colored_label(
+                                        egui::Color32::from_rgb(255, 165, 0),
+                                        "⚠ Contract expires at the end of the season - renew it from the Finance screen",
+                                    );
+                                }
+
+                                ui.add_space(20.0);
+                                ui.heading("Transfers");
+                                let can_leave = team.players.len() > MIN_ROSTER_SIZE;
+                                let payout = termination_payout(player, game_state.current_date);
+                                ui.horizontal(|ui| {
+                                    let list_text = if player.transfer_listed { "Remove from Transfer List" } else { "Transfer List" };
+                                    if ui.button(list_text).clicked() {
+                                        transfer_action = Some(TransferAction::SetListed(player_id, !player.transfer_listed));
+                                    }
+                                    if ui
+                                        .add_enabled(can_leave, egui::Button::new("Loan Out"))
+                                        .on_disabled_hover_text("You need more than five players to let one go")
+                                        .clicked()
+                                    {
+                                        transfer_action = Some(TransferAction::LoanOut(player_id));
+                                    }
+                                    if ui
+                                        .add_enabled(can_leave && payout <= game_state.budget, egui::Button::new(format!("Release (${} payout)", payout)))
+                                        .on_disabled_hover_text("You need more than five players and enough budget for the payout")
+                                        .clicked()
+                                    {
+                                        transfer_action = Some(TransferAction::Release(player_id));
+                                    }
                                 });
+
+                                if player.transfer_listed {
+                                    let mut bids = game_state.bids_for(player_id).peekable();
+                                    if bids.peek().is_none() {
+                                        ui.label("Listed for transfer - no bids yet");
+                                    }
+                                    for bid in bids {
+                                        let club = game_state.get_team(bid.team_id).map_or("", |t| t.name.as_str());
+                                        ui.horizontal(|ui| {
+                                            ui.label(format!(
+                                                "{} offer ${} (until {})",
+                                                club,
+                                                bid.fee,
+                                                bid.expires.format("%Y-%m-%d")
+                                            ));
+                                            if ui.add_enabled(can_leave, egui::Button::new("Accept")).clicked() {
+                                                transfer_action = Some(TransferAction::AcceptBid(bid.id));
+                                            }
+                                            if ui.button("Reject").clicked() {
+                                                transfer_action = Some(TransferAction::RejectBid(bid.id));
+                                            }
+                                        });
+                                    }
+                                }
                             }
                         } else {
                             ui.label("Select a player to view details");
@@ -208,12 +357,22 @@ impl SquadScreen {
             }
         });
 
+        if let Some((player_id, schedule)) = training_change {
+            if let Some(player) = game_state.get_player_by_id_mut(player_id) {
+                player.training = schedule;
+            }
+        }
+
+        if let Some(action) = transfer_action {
+            self.apply_transfer_action(action, game_state);
+        }
+
         // Handle drag cancellation (mouse released outside valid drop zones)
         if self.dragging_player_id.is_some() && ctx.input(|i| i.pointer.any_released()) {
             // If we reach here, the drag ended but wasn't handled by any drop zone
             if let Some(mut team) = game_state.current_team.clone() {
                 if let Some(dragged_id) = self.dragging_player_id {
-                    self.handle_drag_end(&mut team, dragged_id);
+                    self.handle_drag_end(&mut team, game_state, dragged_id);
                     game_state.current_team = Some(team);
                 }
             }
@@ -229,7 +388,26 @@ impl SquadScreen {
         }
     }
 
-    fn show_player_list(&mut self, ui: &mut egui::Ui, team: &mut crate::game::Team, game_state: &GameState, is_starters: bool) {
+    fn apply_transfer_action(&mut self, action: TransferAction, game_state: &mut GameState) {
+        let player_left = match action {
+            TransferAction::SetListed(player_id, listed) => {
+                game_state.set_transfer_listed(player_id, listed);
+                false
+            }
+            TransferAction::LoanOut(player_id) => game_state.loan_out(player_id).is_some(),
+            TransferAction::Release(player_id) => game_state.release_player(player_id).is_some(),
+            TransferAction::AcceptBid(bid_id) => game_state.accept_bid(bid_id),
+            TransferAction::RejectBid(bid_id) => {
+                game_state.reject_bid(bid_id);
+                false
+            }
+        };
+        if player_left {
+            self.selected_player_id = None;
+        }
+    }
+
+    fn show_player_list(&mut self, ui: &mut egui::Ui, team: &mut Team, game_state: &GameState, is_starters: bool) {
         let players: Vec<uuid::Uuid> = if is_starters {
             team.starting_lineup.clone()
         } else {
@@ -291,6 +469,22 @@ impl SquadScreen {
                                 if is_starters {
                                     ui.add(egui::Label::new(format!("Morale: {:?}", player.morale)).selectable(false));
                                 }
+                                ui.add(egui::Label::new(format!("Condition: {}%", player.condition())).selectable(false));
+                                if player.in_final_year(game_state.current_date) {
+                                    ui.add(egui::Label::new(
+                                        egui::RichText::new("Contract expiring").color(egui::Color32::from_rgb(255, 165, 0)),
+                                    ).selectable(false));
+                                }
+                                if let Some(injury) = player.current_injury() {
+                                    ui.add(egui::Label::new(
+                                        egui::RichText::new(format!("Out until {}", injury.return_date.format("%Y-%m-%d")))
+                                            .color(egui::Color32::RED),
+                                    ).selectable(false));
+                                } else if !player.is_available() {
+                                    ui.add(egui::Label::new(
+                                        egui::RichText::new(format!("On break ({}d)", player.break_days)).color(egui::Color32::RED),
+                                    ).selectable(false));
+                                }
                             });
                             ui.add_space(if is_starters { 3.0 } else { 2.0 });
                         });
@@ -343,29 +537,27 @@ impl SquadScreen {
 
                 // Handle drag end
                 if interact_response.drag_stopped() && self.dragging_player_id == Some(player_id) {
-                    self.handle_drag_end(team, player_id);
+                    self.handle_drag_end(team, game_state, player_id);
                 }
 
                 // Update drop target position when hovering during drag
-                if self.dragging_player_id.is_some() && self.dragging_player_id != Some(player_id) {
-                    if interact_response.hovered() {
-                        // Calculate the correct drop position accounting for the dragged player being temporarily removed
-                        let drop_pos = if let Some(_dragged_id) = self.dragging_player_id {
-                            if let Some((origin_is_starters, origin_pos)) = self.drag_origin {
-                                if origin_is_starters == is_starters && origin_is_starters && origin_pos < index {
-                                    // Dragged player was originally before this position in the same list
-                                    index
-                                } else {
-                                    index
-                                }
+                if self.dragging_player_id.is_some() && self.dragging_player_id != Some(player_id) && interact_response.hovered() {
+                    // Calculate the correct drop position accounting for the dragged player being temporarily removed
+                    let drop_pos = if let Some(_dragged_id) = self.dragging_player_id {
+                        if let Some((origin_is_starters, origin_pos)) = self.drag_origin {
+                            if origin_is_starters == is_starters && origin_is_starters && origin_pos < index {
+                                // Dragged player was originally before this position in the same list
+                                index
                             } else {
                                 index
                             }
                         } else {
                             index
-                        };
-                        self.drop_target_position = Some((is_starters, drop_pos));
-                    }
+                        }
+                    } else {
+                        index
+                    };
+                    self.drop_target_position = Some((is_starters, drop_pos));
                 }
 
                 ui.separator();
@@ -416,16 +608,12 @@ impl SquadScreen {
 
             if end_drop_response.hovered() && ui.input(|i| i.pointer.any_released()) {
                 if let Some(dragged_id) = self.dragging_player_id {
-                    self.handle_drag_end(team, dragged_id);
+                    self.handle_drag_end(team, game_state, dragged_id);
                 }
             }
         }
     }
 
-    fn show_drop_zone(&self, ui: &mut egui::Ui) {
-        self.show_drop_zone_with_context(ui, false, false);
-    }
-    
     fn show_drop_zone_with_context(&self, ui: &mut egui::Ui, _is_starters_target: bool, will_cause_substitution: bool) {
         let (_, drop_zone_rect) = ui.allocate_space([ui.available_width(), 40.0].into());
         
@@ -469,9 +657,15 @@ impl SquadScreen {
         });
     }
 
-    fn handle_drag_end(&mut self, team: &mut crate::game::Team, player_id: uuid::Uuid) {
+    fn handle_drag_end(&mut self, team: &mut Team, game_state: &GameState, player_id: uuid::Uuid) {
+        // Unavailable players can't be moved into the lineup
+        let available = game_state.get_player_by_id(player_id).is_some_and(Player::is_available);
+        let drop_target = self
+            .drop_target_position
+            .filter(|&(target_is_starters, _)| available || !target_is_starters);
+
         // Check if we have a valid drop target
-        if let Some((target_is_starters, target_pos)) = self.drop_target_position {
+        if let Some((target_is_starters, target_pos)) = drop_target {
             // Valid drop - perform the move
             self.perform_player_move(team, player_id, target_is_starters, target_pos);
         } else {
@@ -485,7 +679,7 @@ impl SquadScreen {
         self.drag_origin = None;
     }
 
-    fn perform_player_move(&self, team: &mut crate::game::Team, player_id: uuid::Uuid, target_is_starters: bool, target_pos: usize) {
+    fn perform_player_move(&self, team: &mut Team, player_id: uuid::Uuid, target_is_starters: bool, target_pos: usize) {
         const MAX_STARTERS: usize = 5;
         
         // Check if player is currently in starters
@@ -537,7 +731,7 @@ impl SquadScreen {
         // If dropping to bench, just removing from starters is enough since bench is derived
     }
 
-    fn restore_to_original_position(&self, team: &mut crate::game::Team, player_id: uuid::Uuid) {
+    fn restore_to_original_position(&self, team: &mut Team, player_id: uuid::Uuid) {
         if let Some((origin_is_starters, origin_pos)) = self.drag_origin {
             // Remove from wherever it currently is
             team.starting_lineup.retain(|&id| id != player_id);
@@ -552,7 +746,7 @@ impl SquadScreen {
         }
     }
 
-    fn draw_floating_player_card(&self, ctx: &egui::Context, player: &crate::game::Player, is_starters: bool) {
+    fn draw_floating_player_card(&self, ctx: &egui::Context, player: &Player, is_starters: bool) {
         // Get the current pointer position
         let pointer_pos = ctx.input(|i| i.pointer.interact_pos()).unwrap_or_default();
         
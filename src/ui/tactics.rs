@@ -1,23 +1,36 @@
 use eframe::egui;
-use crate::game::GameState;
+use valorant_manager::game::{assign_agents, Agent, AttackStyle, DefenseStyle, EconomicPolicy, GameState, MapTactics, MAP_POOL};
 
 pub struct TacticsScreen {
     selected_map: String,
+    draft: Option<MapTactics>,
 }
 
 impl TacticsScreen {
     pub fn new() -> Self {
         Self {
             selected_map: "Ascent".to_string(),
+            draft: None,
         }
     }
 
     pub fn show(&mut self, ctx: &egui::Context, game_state: &mut GameState) {
+        let mut save_tactics = false;
+
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.heading("Tactics & Strategy");
             ui.separator();
 
-            if let Some(_team) = &game_state.current_team {
+            if let Some(team) = &game_state.current_team {
+                // Start editing from the saved setup whenever the map changes
+                if self.draft.as_ref().map(|d| d.map != self.selected_map).unwrap_or(true) {
+                    self.draft = Some(team.tactics.get_map_tactics(&self.selected_map));
+                }
+                let saved = team.tactics.get_map_tactics(&self.selected_map);
+                let Some(draft) = &mut self.draft else {
+                    return;
+                };
+
                 ui.horizontal(|ui| {
                     // Left panel - Map selection and strategy
                     ui.vertical(|ui| {
@@ -28,8 +41,7 @@ impl TacticsScreen {
                             egui::ComboBox::from_label("")
                                 .selected_text(&self.selected_map)
                                 .show_ui(ui, |ui| {
-                                    let maps = vec!["Ascent", "Bind", "Breeze", "Haven", "Icebox", "Fracture", "Lotus", "Split"];
-                                    for map in maps {
+                                    for map in MAP_POOL {
                                         ui.selectable_value(&mut self.selected_map, map.to_string(), map);
                                     }
                                 });
@@ -65,29 +77,60 @@ impl TacticsScreen {
                         
                         ui.heading("Team Composition");
                         ui.label("Recommended composition for this map:");
-                        
-                        // Show recommended agents based on map
-                        match self.selected_map.as_str() {
-                            "Ascent" => {
-                                ui.label("• Jett (Duelist)");
-                                ui.label("• Sova (Initiator)");
-                                ui.label("• Omen (Controller)");
-                                ui.label("• Killjoy (Sentinel)");
-                                ui.label("• KAY/O (Initiator)");
-                            }
-                            "Bind" => {
-                                ui.label("• Jett (Duelist)");
-                                ui.label("• Sova (Initiator)");
-                                ui.label("• Viper (Controller)");
-                                ui.label("• Sage (Sentinel)");
-                                ui.label("• Killjoy (Sentinel)");
-                            }
-                            _ => {
-                                ui.label("• 1-2 Duelists");
-                                ui.label("• 1 Initiator");
-                                ui.label("• 1 Controller");
-                                ui.label("• 1 Sentinel");
-                            }
+                        let recommended: Vec<&str> = MapTactics::recommended_composition(&self.selected_map)
+                            .iter()
+                            .map(|agent| agent.name())
+                            .collect();
+                        ui.label(recommended.join(", "));
+
+                        ui.add_space(10.0);
+                        ui.label("Your composition:");
+                        for (slot, agent) in draft.composition.iter_mut().enumerate() {
+                            egui::ComboBox::from_id_source(format!("composition_slot_{}", slot))
+                                .selected_text(format!("{} ({:?})", agent.name(), agent.role()))
+                                .show_ui(ui, |ui| {
+                                    for option in Agent::ALL {
+                                        let favored = if option.favored_maps().contains(&self.selected_map.as_str()) {
+                                            " ★"
+                                        } else {
+                                            ""
+                                        };
+                                        ui.selectable_value(
+                                            agent,
+                                            option,
+                                            format!("{} ({:?}){}", option.name(), option.role(), favored),
+                                        );
+                                    }
+                                });
+                        }
+
+                        let roles = draft.role_composition();
+                        ui.label(format!(
+                            "Duelists: {}  Initiators: {}  Controllers: {}  Sentinels: {}",
+                            roles.duelist_count, roles.initiator_count, roles.controller_count, roles.sentinel_count
+                        ));
+                        if !draft.is_valid_composition() {
+                            ui.colored_label(egui::Color32::RED, "✗ Each agent can only be picked once");
+                        } else if roles.is_balanced() {
+                            ui.colored_label(egui::Color32::GREEN, "✓ Balanced composition");
+                        } else {
+                            ui.colored_label(egui::Color32::YELLOW, "⚠ Unbalanced composition - expect weaker rounds");
+                        }
+
+                        ui.add_space(10.0);
+                        ui.label("Agent assignments:");
+                        let lineup: Vec<_> = team
+                            .starting_lineup
+                            .iter()
+                            .filter_map(|&id| game_state.get_player_by_id(id))
+                            .collect();
+                        for (player, agent) in assign_agents(&lineup, &draft.composition) {
+                            ui.label(format!(
+                                "{} → {} ({}/20)",
+                                player.name,
+                                agent.name(),
+                                player.get_agent_proficiency(agent)
+                            ));
                         }
                     });
 
@@ -97,15 +140,13 @@ impl TacticsScreen {
                     ui.vertical(|ui| {
                         ui.heading("Current Lineup");
                         
-                        if let Some(team) = &game_state.current_team {
-                            for &player_id in &team.starting_lineup {
-                                if let Some(player) = game_state.get_player_by_id(player_id) {
-                                    ui.horizontal(|ui| {
-                                        ui.label(&player.name);
-                                        ui.label(format!("({:?})", player.preferred_role));
-                                        ui.label(format!("Overall: {}", player.attributes.overall_rating()));
-                                    });
-                                }
+                        for &player_id in &team.starting_lineup {
+                            if let Some(player) = game_state.get_player_by_id(player_id) {
+                                ui.horizontal(|ui| {
+                                    ui.label(&player.name);
+                                    ui.label(format!("({:?})", player.preferred_role));
+                                    ui.label(format!("Overall: {}", player.attributes.overall_rating()));
+                                });
                             }
                         }
                         
@@ -114,30 +155,48 @@ impl TacticsScreen {
                         ui.heading("Tactical Instructions");
                         
                         ui.label("Attack Side:");
-                        ui.checkbox(&mut true, "Default spread");
-                        ui.checkbox(&mut false, "Fast execute");
-                        ui.checkbox(&mut false, "Slow default");
+                        for style in [AttackStyle::DefaultSpread, AttackStyle::FastExecute, AttackStyle::SlowDefault] {
+                            ui.radio_value(&mut draft.attack_style, style, style.name());
+                        }
                         
                         ui.add_space(10.0);
                         
                         ui.label("Defense Side:");
-                        ui.checkbox(&mut true, "Standard setup");
-                        ui.checkbox(&mut false, "Aggressive angles");
-                        ui.checkbox(&mut false, "Stack sites");
+                        for style in [DefenseStyle::Standard, DefenseStyle::AggressiveAngles, DefenseStyle::StackSites] {
+                            ui.radio_value(&mut draft.defense_style, style, style.name());
+                        }
                         
                         ui.add_space(20.0);
                         
                         ui.heading("Economic Strategy");
                         
                         ui.label("Buy Phase Preferences:");
-                        ui.checkbox(&mut true, "Conservative economy");
-                        ui.checkbox(&mut false, "Aggressive force buys");
-                        ui.checkbox(&mut false, "Save for full buys");
+                        for policy in [
+                            EconomicPolicy::Conservative,
+                            EconomicPolicy::AggressiveForceBuys,
+                            EconomicPolicy::SaveForFullBuys,
+                        ] {
+                            ui.radio_value(&mut draft.economic_policy, policy, policy.name());
+                        }
                         
                         ui.add_space(20.0);
-                        
-                        if ui.button("Save Tactical Setup").clicked() {
-                            // TODO: Save tactical preferences
+
+                        let unsaved = draft.attack_style != saved.attack_style
+                            || draft.defense_style != saved.defense_style
+                            || draft.economic_policy != saved.economic_policy
+                            || draft.composition != saved.composition;
+
+                        ui.horizontal(|ui| {
+                            let can_save = unsaved && draft.is_valid_composition();
+                            if ui.add_enabled(can_save, egui::Button::new("Save Tactical Setup")).clicked() {
+                                save_tactics = true;
+                            }
+                            if ui.add_enabled(unsaved, egui::Button::new("Discard Changes")).clicked() {
+                                *draft = saved.clone();
+                            }
+                        });
+                        if unsaved {
+                            ui.colored_label(egui::Color32::YELLOW, "Unsaved changes");
                         }
                     });
                 });
@@ -145,5 +204,11 @@ impl TacticsScreen {
                 ui.label("No team selected");
             }
         });
+
+        if save_tactics {
+            if let (Some(team), Some(draft)) = (&mut game_state.current_team, &self.draft) {
+                team.tactics.set_map_tactics(draft.clone());
+            }
+        }
     }
-} 
\ No newline at end of file
+}